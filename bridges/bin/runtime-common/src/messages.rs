@@ -257,6 +257,15 @@ pub mod target {
 		maximal_extrinsic_size / 3 * 2
 	}
 
+	/// Maximal size of inbound message payload.
+	pub struct FromBridgedChainMaximalInboundPayloadSize<B>(PhantomData<B>);
+
+	impl<B: MessageBridge> Get<u32> for FromBridgedChainMaximalInboundPayloadSize<B> {
+		fn get() -> u32 {
+			maximal_incoming_message_size(UnderlyingChainOf::<ThisChain<B>>::max_extrinsic_size())
+		}
+	}
+
 	/// `SourceHeaderChain` implementation that is using default types and perform default checks.
 	pub struct SourceHeaderChainAdapter<B>(PhantomData<B>);
 
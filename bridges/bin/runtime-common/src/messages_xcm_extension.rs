@@ -26,7 +26,7 @@ use bp_messages::{
 	target_chain::{DispatchMessage, MessageDispatch},
 	LaneId, MessageNonce,
 };
-use bp_runtime::messages::MessageDispatchResult;
+use bp_runtime::messages::{DispatchErrorCode, MessageDispatchResult};
 pub use bp_xcm_bridge_hub::XcmAsPlainPayload;
 use bp_xcm_bridge_hub_router::XcmChannelStatusProvider;
 use codec::{Decode, Encode};
@@ -96,17 +96,18 @@ impl<
 				return MessageDispatchResult {
 					unspent_weight: Weight::zero(),
 					dispatch_level_result: XcmBlobMessageDispatchResult::InvalidPayload,
+					dispatch_error: Some(DispatchErrorCode::InvalidPayload),
 				}
 			},
 		};
-		let dispatch_level_result = match BlobDispatcher::dispatch_blob(payload) {
+		let (dispatch_level_result, dispatch_error) = match BlobDispatcher::dispatch_blob(payload) {
 			Ok(_) => {
 				log::debug!(
 					target: crate::LOG_TARGET_BRIDGE_DISPATCH,
 					"[XcmBlobMessageDispatch] DispatchBlob::dispatch_blob was ok - message_nonce: {:?}",
 					message.key.nonce
 				);
-				XcmBlobMessageDispatchResult::Dispatched
+				(XcmBlobMessageDispatchResult::Dispatched, None)
 			},
 			Err(e) => {
 				log::error!(
@@ -114,10 +115,17 @@ impl<
 					"[XcmBlobMessageDispatch] DispatchBlob::dispatch_blob failed, error: {:?} - message_nonce: {:?}",
 					e, message.key.nonce
 				);
-				XcmBlobMessageDispatchResult::NotDispatched(Some(e))
+				(
+					XcmBlobMessageDispatchResult::NotDispatched(Some(e)),
+					Some(DispatchErrorCode::NotDispatched),
+				)
 			},
 		};
-		MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result }
+		MessageDispatchResult {
+			unspent_weight: Weight::zero(),
+			dispatch_level_result,
+			dispatch_error,
+		}
 	}
 }
 
@@ -23,7 +23,10 @@ use crate::messages::{
 		FromThisChainMaximalOutboundPayloadSize, FromThisChainMessagePayload,
 		TargetHeaderChainAdapter,
 	},
-	target::{FromBridgedChainMessagePayload, SourceHeaderChainAdapter},
+	target::{
+		FromBridgedChainMaximalInboundPayloadSize, FromBridgedChainMessagePayload,
+		SourceHeaderChainAdapter,
+	},
 	BridgedChainWithMessages, HashOf, MessageBridge, ThisChainWithMessages,
 };
 
@@ -210,6 +213,7 @@ impl pallet_bridge_messages::Config for TestRuntime {
 	type OutboundPayload = FromThisChainMessagePayload;
 
 	type InboundPayload = FromBridgedChainMessagePayload;
+	type MaxInboundMessageSize = FromBridgedChainMaximalInboundPayloadSize<OnThisChainBridge>;
 	type InboundRelayer = BridgedChainAccountId;
 	type DeliveryPayments = ();
 
@@ -220,6 +224,7 @@ impl pallet_bridge_messages::Config for TestRuntime {
 		ConstU64<100_000>,
 	>;
 	type OnMessagesDelivered = ();
+	type MaxDeliveryProofsPerRelayerPerBlock = ();
 
 	type SourceHeaderChain = SourceHeaderChainAdapter<OnThisChainBridge>;
 	type MessageDispatch = DummyMessageDispatch;
@@ -258,7 +263,11 @@ impl MessageDispatch for DummyMessageDispatch {
 	fn dispatch(
 		_: DispatchMessage<Self::DispatchPayload>,
 	) -> MessageDispatchResult<Self::DispatchLevelResult> {
-		MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result: () }
+		MessageDispatchResult {
+			unspent_weight: Weight::zero(),
+			dispatch_level_result: (),
+			dispatch_error: None,
+		}
 	}
 }
 
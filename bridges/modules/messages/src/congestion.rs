@@ -0,0 +1,141 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Adapters that report the state of message lanes as the congestion of XCM channels.
+
+use crate::{Config, InboundLanes};
+
+use bp_messages::{LaneId, MessageNonce};
+use frame_support::traits::Get;
+use sp_std::marker::PhantomData;
+
+/// Adapter implementation for `bp_xcm_bridge_hub_router::XcmChannelStatusProvider` which reports
+/// congestion when the inbound lane `Lane` is close to its capacity.
+///
+/// The lane is close to its capacity when either the number of unconfirmed messages or the number
+/// of unrewarded relayer entries at the lane reaches three quarters of its maximal value. Once
+/// the lane is full, the delivery transactions are rejected, so the chains that have the messages
+/// pallet deployed may use this adapter to apply backpressure before that happens.
+pub struct InboundLaneChannelStatusProvider<T, I, Lane>(PhantomData<(T, I, Lane)>);
+
+impl<T: Config<I>, I: 'static, Lane: Get<LaneId>>
+	bp_xcm_bridge_hub_router::XcmChannelStatusProvider
+	for InboundLaneChannelStatusProvider<T, I, Lane>
+{
+	fn is_congested() -> bool {
+		let data = InboundLanes::<T, I>::get(Lane::get()).0;
+
+		let unconfirmed_messages =
+			data.last_delivered_nonce().saturating_sub(data.last_confirmed_nonce);
+		if is_near_capacity(unconfirmed_messages, T::MaxUnconfirmedMessagesAtInboundLane::get()) {
+			return true
+		}
+
+		let unrewarded_relayer_entries = data.relayers.len() as MessageNonce;
+		is_near_capacity(
+			unrewarded_relayer_entries,
+			T::MaxUnrewardedRelayerEntriesAtInboundLane::get(),
+		)
+	}
+}
+
+/// Returns true if `occupied` is at least three quarters of `capacity`.
+fn is_near_capacity(occupied: MessageNonce, capacity: MessageNonce) -> bool {
+	occupied.saturating_mul(4) >= capacity.saturating_mul(3)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use bp_messages::InboundLaneData;
+	use bp_xcm_bridge_hub_router::XcmChannelStatusProvider;
+
+	frame_support::parameter_types! {
+		pub const TestLane: LaneId = TEST_LANE_ID;
+	}
+
+	type TestChannelStatusProvider = InboundLaneChannelStatusProvider<TestRuntime, (), TestLane>;
+
+	#[test]
+	fn empty_inbound_lane_is_not_congested() {
+		run_test(|| {
+			assert!(!TestChannelStatusProvider::is_congested());
+		});
+	}
+
+	#[test]
+	fn inbound_lane_with_too_many_unconfirmed_messages_is_congested() {
+		run_test(|| {
+			let max_messages = MaxUnconfirmedMessagesAtInboundLane::get();
+			InboundLanes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![unrewarded_relayer(1, max_messages / 2, TEST_RELAYER_A)]
+						.into_iter()
+						.collect(),
+				},
+			);
+			assert!(!TestChannelStatusProvider::is_congested());
+
+			InboundLanes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![unrewarded_relayer(1, max_messages - 1, TEST_RELAYER_A)]
+						.into_iter()
+						.collect(),
+				},
+			);
+			assert!(TestChannelStatusProvider::is_congested());
+		});
+	}
+
+	#[test]
+	fn inbound_lane_with_too_many_unrewarded_relayers_is_congested() {
+		run_test(|| {
+			let max_entries = MaxUnrewardedRelayerEntriesAtInboundLane::get();
+			InboundLanes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: (1..max_entries)
+						.map(|nonce| unrewarded_relayer(nonce, nonce, TEST_RELAYER_A))
+						.collect(),
+				},
+			);
+			assert!(TestChannelStatusProvider::is_congested());
+		});
+	}
+
+	#[test]
+	fn other_inbound_lanes_do_not_affect_congestion() {
+		run_test(|| {
+			let max_messages = MaxUnconfirmedMessagesAtInboundLane::get();
+			InboundLanes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID_2,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![unrewarded_relayer(1, max_messages, TEST_RELAYER_A)]
+						.into_iter()
+						.collect(),
+				},
+			);
+			assert!(!TestChannelStatusProvider::is_congested());
+		});
+	}
+}
@@ -63,10 +63,11 @@ use bp_messages::{
 	UnrewardedRelayersState, VerificationError,
 };
 use bp_runtime::{
-	BasicOperatingMode, ChainId, OwnedBridgeModule, PreComputedSize, RangeInclusiveExt, Size,
+	messages::DispatchErrorCode, BasicOperatingMode, ChainId, OwnedBridgeModule,
+	PreComputedSize, RangeInclusiveExt, Size,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::PostDispatchInfo, ensure, fail, traits::Get, DefaultNoBound};
+use frame_support::{dispatch::PostDispatchInfo, ensure, traits::Get, DefaultNoBound};
 use sp_runtime::traits::UniqueSaturatedFrom;
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -74,6 +75,7 @@ mod inbound_lane;
 mod outbound_lane;
 mod weights_ext;
 
+pub mod congestion;
 pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -143,6 +145,10 @@ pub mod pallet {
 
 		/// Payload type of inbound messages. This payload is dispatched on this chain.
 		type InboundPayload: Decode;
+		/// Maximal encoded size of the inbound message payload. Messages proof, containing
+		/// larger message, is rejected before any of its messages is dispatched.
+		#[pallet::constant]
+		type MaxInboundMessageSize: Get<u32>;
 		/// Identifier of relayer that deliver messages to this chain. Relayer reward is paid on the
 		/// bridged chain.
 		type InboundRelayer: Parameter + MaxEncodedLen;
@@ -157,6 +163,10 @@ pub mod pallet {
 		type DeliveryConfirmationPayments: DeliveryConfirmationPayments<Self::AccountId>;
 		/// Delivery confirmation callback.
 		type OnMessagesDelivered: OnMessagesDelivered;
+		/// Maximal number of messages delivery proofs that a single relayer may submit within a
+		/// single block. `None` means that the number of proofs is not limited.
+		#[pallet::constant]
+		type MaxDeliveryProofsPerRelayerPerBlock: Get<Option<u32>>;
 
 		// Types that are used by inbound_lane (on target chain).
 
@@ -191,6 +201,22 @@ pub mod pallet {
 	where
 		u32: TryFrom<BlockNumberFor<T>>,
 	{
+		fn on_initialize(_block: BlockNumberFor<T>) -> Weight {
+			// the `DeliveryProofsInBlock` is cleared in the `on_finalize`. Removal of every entry
+			// is paid by the `receive_messages_delivery_proof` call, which has inserted it
+			if T::MaxDeliveryProofsPerRelayerPerBlock::get().is_some() {
+				T::DbWeight::get().writes(1)
+			} else {
+				Weight::zero()
+			}
+		}
+
+		fn on_finalize(_block: BlockNumberFor<T>) {
+			if T::MaxDeliveryProofsPerRelayerPerBlock::get().is_some() {
+				let _ = DeliveryProofsInBlock::<T, I>::clear(u32::MAX, None);
+			}
+		}
+
 		fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
 			// we'll need at least to read outbound lane state, kill a message and update lane state
 			let db_weight = T::DbWeight::get();
@@ -260,12 +286,26 @@ pub mod pallet {
 		/// - the proof verification procedure returns an error - e.g. because header used to craft
 		///   proof is not imported by the associated finality pallet;
 		///
-		/// - the `dispatch_weight` argument is not sufficient to dispatch all bundled messages.
+		/// - the proof contains a message, which is larger than the `MaxInboundMessageSize`;
+		///
+		/// - the `dispatch_weight` argument is not sufficient to dispatch any of bundled messages.
 		///
 		/// The call may succeed, but some messages may not be delivered e.g. if they are not fit
 		/// into the unrewarded relayers vector.
+		///
+		/// The `dispatch_weight` is limited by the normal-class weight that a single extrinsic may
+		/// use, so the call with a proof that doesn't fit into a block is still accepted. If the
+		/// (limited) `dispatch_weight` is only sufficient to dispatch some first messages of the
+		/// lane, the remaining messages of this lane are left undelivered and the relayer is
+		/// refunded for them. The delivery progress is recorded in the `InboundLanes`, so they may
+		/// be delivered by the subsequent transaction. This allows relayer to split a large proof
+		/// into several transactions, each fitting into the block.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight))]
+		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(
+			proof,
+			*messages_count,
+			Pallet::<T, I>::dispatch_weight_budget(proof, *messages_count, *dispatch_weight),
+		))]
 		pub fn receive_messages_proof(
 			origin: OriginFor<T>,
 			relayer_id_at_bridged_chain: T::InboundRelayer,
@@ -285,6 +325,11 @@ pub mod pallet {
 			// if message dispatcher is currently inactive, we won't accept any messages
 			ensure!(T::MessageDispatch::is_active(), Error::<T, I>::MessageDispatchInactive);
 
+			// only the part of declared dispatch weight, which fits into the extrinsic, is
+			// reserved for the call and may be spent on dispatching messages
+			let dispatch_weight =
+				Self::dispatch_weight_budget(&proof, messages_count, dispatch_weight);
+
 			// why do we need to know the weight of this (`receive_messages_proof`) call? Because
 			// we may want to return some funds for not-dispatching (or partially dispatching) some
 			// messages to the call origin (relayer). And this is done by returning actual weight
@@ -303,21 +348,15 @@ pub mod pallet {
 			let mut actual_weight = declared_weight;
 
 			// verify messages proof && convert proof into messages
-			let messages = verify_and_decode_messages_proof::<
-				T::SourceHeaderChain,
-				T::InboundPayload,
-			>(proof, messages_count)
-			.map_err(|err| {
-				log::trace!(target: LOG_TARGET, "Rejecting invalid messages proof: {:?}", err,);
-
-				Error::<T, I>::InvalidMessagesProof
-			})?;
+			let messages = verify_and_decode_messages_proof::<T, I>(proof, messages_count)?;
 
 			// dispatch messages and (optionally) update lane(s) state(s)
 			let mut total_messages = 0;
 			let mut valid_messages = 0;
 			let mut messages_received_status = Vec::with_capacity(messages.len());
+			let mut skipped_messages: MessageNonce = 0;
 			let mut dispatch_weight_left = dispatch_weight;
+			let mut is_dispatch_weight_exhausted = false;
 			for (lane_id, lane_data) in messages {
 				let mut lane = inbound_lane::<T, I>(lane_id);
 
@@ -341,15 +380,16 @@ pub mod pallet {
 					}
 				}
 
+				let lane_messages_count = lane_data.messages.len();
 				let mut lane_messages_received_status =
-					ReceivedMessages::new(lane_id, Vec::with_capacity(lane_data.messages.len()));
-				for mut message in lane_data.messages {
+					ReceivedMessages::new(lane_id, Vec::with_capacity(lane_messages_count));
+				for (index, mut message) in lane_data.messages.into_iter().enumerate() {
 					debug_assert_eq!(message.key.lane_id, lane_id);
-					total_messages += 1;
 
 					// ensure that relayer has declared enough weight for dispatching next message
 					// on this lane. We can't dispatch lane messages out-of-order, so if declared
-					// weight is not enough, let's move to next lane
+					// weight is not enough, let's move to next lane. Remaining lane messages may
+					// be delivered by the next transaction
 					let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message);
 					if message_dispatch_weight.any_gt(dispatch_weight_left) {
 						log::trace!(
@@ -360,9 +400,13 @@ pub mod pallet {
 							dispatch_weight_left,
 						);
 
-						fail!(Error::<T, I>::InsufficientDispatchWeight);
+						skipped_messages += (lane_messages_count - index) as MessageNonce;
+						is_dispatch_weight_exhausted = true;
+						break
 					}
 
+					total_messages += 1;
+
 					let receival_result = lane.receive_message::<T::MessageDispatch>(
 						&relayer_id_at_bridged_chain,
 						message.key.nonce,
@@ -378,6 +422,11 @@ pub mod pallet {
 					let unspent_weight = match &receival_result {
 						ReceptionResult::Dispatched(dispatch_result) => {
 							valid_messages += 1;
+							Self::deposit_event(Event::MessageDispatched {
+								lane_id,
+								nonce: message.key.nonce,
+								result: dispatch_result.outcome(),
+							});
 							dispatch_result.unspent_weight
 						},
 						ReceptionResult::InvalidNonce |
@@ -394,6 +443,19 @@ pub mod pallet {
 				messages_received_status.push(lane_messages_received_status);
 			}
 
+			// if declared weight is not enough to dispatch even a single message, the relayer
+			// is just wasting block space
+			ensure!(
+				total_messages != 0 || !is_dispatch_weight_exhausted,
+				Error::<T, I>::InsufficientDispatchWeight
+			);
+
+			// skipped messages are not delivered, so relayer is refunded for their delivery (the
+			// dispatch weight of skipped messages has never been spent)
+			actual_weight = actual_weight.saturating_sub(
+				T::WeightInfo::receive_messages_proof_messages_overhead(skipped_messages),
+			);
+
 			// let's now deal with relayer payments
 			T::DeliveryPayments::pay_reward(
 				relayer_id_at_this_chain,
@@ -421,7 +483,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::receive_messages_delivery_proof_weight(
 			proof,
 			relayers_state,
-		))]
+		).saturating_add(Pallet::<T, I>::delivery_proofs_limit_weight()))]
 		pub fn receive_messages_delivery_proof(
 			origin: OriginFor<T>,
 			proof: MessagesDeliveryProofOf<T, I>,
@@ -431,6 +493,7 @@ pub mod pallet {
 
 			let proof_size = proof.size();
 			let confirmation_relayer = ensure_signed(origin)?;
+			Self::ensure_delivery_proof_allowed(&confirmation_relayer)?;
 			let (lane_id, lane_data) = T::TargetHeaderChain::verify_messages_delivery_proof(proof)
 				.map_err(|err| {
 					log::trace!(
@@ -503,7 +566,8 @@ pub mod pallet {
 			let actual_weight = T::WeightInfo::receive_messages_delivery_proof_weight(
 				&PreComputedSize(proof_size as usize),
 				&relayers_state,
-			);
+			)
+			.saturating_add(Self::delivery_proofs_limit_weight());
 
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
 		}
@@ -519,6 +583,15 @@ pub mod pallet {
 			/// Nonce of accepted message.
 			nonce: MessageNonce,
 		},
+		/// Message from the bridged chain has been dispatched.
+		MessageDispatched {
+			/// Lane, which has received the message.
+			lane_id: LaneId,
+			/// Nonce of dispatched message.
+			nonce: MessageNonce,
+			/// Dispatch result, reported by the message dispatcher.
+			result: Result<(), DispatchErrorCode>,
+		},
 		/// Messages have been received from the bridged chain.
 		MessagesReceived(
 			/// Result of received messages dispatch.
@@ -558,7 +631,7 @@ pub mod pallet {
 		/// `receive_messages_delivery_proof` call.
 		InvalidUnrewardedRelayersState,
 		/// The cumulative dispatch weight, passed by relayer is not enough to cover dispatch
-		/// of all bundled messages.
+		/// of any of bundled messages.
 		InsufficientDispatchWeight,
 		/// The message someone is trying to work with (i.e. increase fee) is not yet sent.
 		MessageIsNotYetSent,
@@ -566,6 +639,12 @@ pub mod pallet {
 		ReceptionConfirmation(ReceptionConfirmationError),
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
+		/// The messages proof contains a message, which is larger than the
+		/// `MaxInboundMessageSize`.
+		MessageTooLarge,
+		/// The relayer has already submitted `MaxDeliveryProofsPerRelayerPerBlock` messages
+		/// delivery proofs in the current block.
+		TooManyDeliveryProofsInBlock,
 	}
 
 	/// Optional pallet owner.
@@ -626,6 +705,15 @@ pub mod pallet {
 	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, MessageKey, StoredMessagePayload<T, I>>;
 
+	/// Map of relayer => the number of messages delivery proofs, submitted by this relayer in the
+	/// current block.
+	///
+	/// Only maintained when `MaxDeliveryProofsPerRelayerPerBlock` is set. The map is cleared on
+	/// block finalization.
+	#[pallet::storage]
+	pub type DeliveryProofsInBlock<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -677,6 +765,60 @@ pub mod pallet {
 		pub fn inbound_lane_data(lane: LaneId) -> InboundLaneData<T::InboundRelayer> {
 			InboundLanes::<T, I>::get(lane).0
 		}
+
+		/// Ensure that the relayer has not yet reached the limit of messages delivery proofs in
+		/// the current block and account the new proof.
+		fn ensure_delivery_proof_allowed(relayer: &T::AccountId) -> Result<(), Error<T, I>> {
+			let Some(max_proofs) = T::MaxDeliveryProofsPerRelayerPerBlock::get() else {
+				return Ok(())
+			};
+
+			let proofs_in_block = DeliveryProofsInBlock::<T, I>::get(relayer);
+			ensure!(proofs_in_block < max_proofs, Error::<T, I>::TooManyDeliveryProofsInBlock);
+
+			DeliveryProofsInBlock::<T, I>::insert(relayer, proofs_in_block.saturating_add(1));
+			Ok(())
+		}
+
+		/// Additional weight of the `receive_messages_delivery_proof` call, spent on checking the
+		/// per-relayer limit of messages delivery proofs and on removing the proofs counter on
+		/// block finalization.
+		fn delivery_proofs_limit_weight() -> Weight {
+			if T::MaxDeliveryProofsPerRelayerPerBlock::get().is_some() {
+				T::DbWeight::get().reads_writes(1, 2)
+			} else {
+				Weight::zero()
+			}
+		}
+
+		/// Returns the part of the declared dispatch weight that may be spent by the
+		/// `receive_messages_proof` call. The call with this dispatch weight never exceeds the
+		/// normal-class weight that a single extrinsic may use, so it may be included into a
+		/// block even if the declared dispatch weight doesn't fit there.
+		pub fn dispatch_weight_budget(
+			proof: &MessagesProofOf<T, I>,
+			messages_count: u32,
+			dispatch_weight: Weight,
+		) -> Weight {
+			let block_weights = T::BlockWeights::get();
+			let normal_weights = block_weights.get(DispatchClass::Normal);
+			let Some(max_extrinsic_weight) =
+				normal_weights.max_extrinsic.or(normal_weights.max_total)
+			else {
+				return dispatch_weight
+			};
+
+			let delivery_weight = T::WeightInfo::receive_messages_proof_weight(
+				proof,
+				messages_count,
+				Weight::zero(),
+			);
+			dispatch_weight.min(
+				max_extrinsic_weight
+					.saturating_sub(normal_weights.base_extrinsic)
+					.saturating_sub(delivery_weight),
+			)
+		}
 	}
 
 	/// Get-parameter that returns number of active outbound lanes that the pallet maintains.
@@ -887,27 +1029,51 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 }
 
 /// Verify messages proof and return proved messages with decoded payload.
-fn verify_and_decode_messages_proof<Chain: SourceHeaderChain, DispatchPayload: Decode>(
-	proof: Chain::MessagesProof,
+fn verify_and_decode_messages_proof<T: Config<I>, I: 'static>(
+	proof: MessagesProofOf<T, I>,
 	messages_count: u32,
-) -> Result<ProvedMessages<DispatchMessage<DispatchPayload>>, VerificationError> {
+) -> Result<ProvedMessages<DispatchMessage<T::InboundPayload>>, Error<T, I>> {
 	// `receive_messages_proof` weight formula and `MaxUnconfirmedMessagesAtInboundLane` check
 	// guarantees that the `message_count` is sane and Vec<Message> may be allocated.
 	// (tx with too many messages will either be rejected from the pool, or will fail earlier)
-	Chain::verify_messages_proof(proof, messages_count).map(|messages_by_lane| {
-		messages_by_lane
-			.into_iter()
-			.map(|(lane, lane_data)| {
-				(
-					lane,
-					ProvedLaneMessages {
-						lane_state: lane_data.lane_state,
-						messages: lane_data.messages.into_iter().map(Into::into).collect(),
-					},
-				)
-			})
-			.collect()
-	})
+	let messages_by_lane = T::SourceHeaderChain::verify_messages_proof(proof, messages_count)
+		.map_err(|err| {
+			log::trace!(target: LOG_TARGET, "Rejecting invalid messages proof: {:?}", err,);
+
+			Error::<T, I>::InvalidMessagesProof
+		})?;
+
+	// the bridged chain may be misbehaving, so let's not trust it and reject oversized messages
+	// before decoding and dispatching them
+	let max_message_size = T::MaxInboundMessageSize::get() as usize;
+	let oversized_message = messages_by_lane
+		.values()
+		.flat_map(|lane_data| lane_data.messages.iter())
+		.find(|message| message.payload.len() > max_message_size);
+	if let Some(oversized_message) = oversized_message {
+		log::trace!(
+			target: LOG_TARGET,
+			"Rejecting messages proof with oversized message {:?}: {} bytes, maximal {} bytes",
+			oversized_message.key,
+			oversized_message.payload.len(),
+			max_message_size,
+		);
+
+		return Err(Error::<T, I>::MessageTooLarge)
+	}
+
+	Ok(messages_by_lane
+		.into_iter()
+		.map(|(lane, lane_data)| {
+			(
+				lane,
+				ProvedLaneMessages {
+					lane_state: lane_data.lane_state,
+					messages: lane_data.messages.into_iter().map(Into::into).collect(),
+				},
+			)
+		})
+		.collect())
 }
 
 #[cfg(test)]
@@ -916,12 +1082,13 @@ mod tests {
 	use crate::{
 		mock::{
 			inbound_unrewarded_relayers_state, message, message_payload, run_test,
-			unrewarded_relayer, AccountId, DbWeight, RuntimeEvent as TestEvent, RuntimeOrigin,
+			unrewarded_relayer, AccountId, DbWeight, MaxDeliveryProofsPerRelayerPerBlock,
+			MaxExtrinsicWeight, RuntimeEvent as TestEvent, RuntimeOrigin,
 			TestDeliveryConfirmationPayments, TestDeliveryPayments, TestMessageDispatch,
-			TestMessagesDeliveryProof, TestMessagesProof, TestOnMessagesDelivered, TestRelayer,
-			TestRuntime, TestWeightInfo, MAX_OUTBOUND_PAYLOAD_SIZE,
-			PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD, TEST_LANE_ID, TEST_LANE_ID_2,
-			TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
+			TestMessagesDeliveryProof, TestMessagesProof, TestOnMessagesDelivered, TestPayload,
+			TestRelayer, TestRuntime, TestWeightInfo, MAX_INBOUND_MESSAGE_SIZE,
+			MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD,
+			TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
 		},
 		outbound_lane::ReceptionConfirmationError,
 	};
@@ -932,7 +1099,7 @@ mod tests {
 	use bp_test_utils::generate_owned_bridge_module_tests;
 	use frame_support::{
 		assert_noop, assert_ok,
-		dispatch::Pays,
+		dispatch::{GetDispatchInfo, Pays},
 		storage::generator::{StorageMap, StorageValue},
 		traits::Hooks,
 		weights::Weight,
@@ -1172,6 +1339,60 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn receive_messages_proof_emits_event_for_successfully_dispatched_message() {
+		run_test(|| {
+			get_ready_for_events();
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+
+			assert!(System::<TestRuntime>::events().contains(&EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::MessageDispatched {
+					lane_id: TEST_LANE_ID,
+					nonce: 1,
+					result: Ok(()),
+				}),
+				topics: vec![],
+			}));
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_emits_event_for_failed_message_dispatch() {
+		run_test(|| {
+			get_ready_for_events();
+
+			let mut payload = REGULAR_PAYLOAD;
+			payload.dispatch_result.dispatch_error = Some(DispatchErrorCode::NotDispatched);
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, payload.clone())]).into(),
+				1,
+				payload.declared_weight,
+			));
+
+			assert!(System::<TestRuntime>::events().contains(&EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::MessageDispatched {
+					lane_id: TEST_LANE_ID,
+					nonce: 1,
+					result: Err(DispatchErrorCode::NotDispatched),
+				}),
+				topics: vec![],
+			}));
+			// the message is still delivered, even though its dispatch has failed
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0.last_delivered_nonce(), 1);
+		});
+	}
+
 	#[test]
 	fn receive_messages_proof_updates_confirmed_message_nonce() {
 		run_test(|| {
@@ -1288,6 +1509,50 @@ mod tests {
 		});
 	}
 
+	/// Returns payload with given encoded size.
+	fn payload_of_size(size: u32) -> TestPayload {
+		let mut payload = REGULAR_PAYLOAD;
+		// the length of `extra` is encoded using 2 bytes, instead of 1 byte for empty vector
+		payload.extra = vec![0; (size - payload.encoded_size() as u32 - 1) as usize];
+		assert_eq!(payload.encoded_size() as u32, size);
+		payload
+	}
+
+	#[test]
+	fn receive_messages_proof_accepts_message_of_maximal_size() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, payload_of_size(MAX_INBOUND_MESSAGE_SIZE))]).into(),
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0.last_delivered_nonce(), 1);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_rejects_oversized_message() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime, ()>::receive_messages_proof(
+					RuntimeOrigin::signed(1),
+					TEST_RELAYER_A,
+					Ok(vec![
+						message(1, REGULAR_PAYLOAD),
+						message(2, payload_of_size(MAX_INBOUND_MESSAGE_SIZE + 1)),
+					])
+					.into(),
+					2,
+					REGULAR_PAYLOAD.declared_weight * 2,
+				),
+				Error::<TestRuntime, ()>::MessageTooLarge,
+			);
+		});
+	}
+
 	#[test]
 	fn receive_messages_proof_rejects_proof_with_too_many_messages() {
 		run_test(|| {
@@ -1403,6 +1668,52 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn receive_messages_delivery_proof_rejects_relayer_above_per_block_limit() {
+		run_test(|| {
+			MaxDeliveryProofsPerRelayerPerBlock::set(&Some(1));
+			send_regular_message(TEST_LANE_ID);
+
+			let receive_delivery_proof = |relayer| {
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(relayer),
+					TestMessagesDeliveryProof(Ok((
+						TEST_LANE_ID,
+						InboundLaneData {
+							relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+								.into_iter()
+								.collect(),
+							..Default::default()
+						},
+					))),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 1,
+						messages_in_oldest_entry: 1,
+						total_messages: 1,
+						last_delivered_nonce: 1,
+					},
+				)
+			};
+
+			// the relayer is allowed to submit a single proof in the block
+			assert_ok!(receive_delivery_proof(1));
+			assert_noop!(
+				receive_delivery_proof(1),
+				Error::<TestRuntime, ()>::TooManyDeliveryProofsInBlock,
+			);
+
+			// other relayers are not affected by the limit
+			assert_ok!(receive_delivery_proof(2));
+
+			// and the limit is reset in the next block
+			Pallet::<TestRuntime>::on_finalize(1);
+			assert_eq!(DeliveryProofsInBlock::<TestRuntime, ()>::iter().count(), 0);
+			System::<TestRuntime>::set_block_number(2);
+			assert_eq!(Pallet::<TestRuntime>::on_initialize(2), DbWeight::get().writes(1));
+			assert_ok!(receive_delivery_proof(1));
+		});
+	}
+
 	#[test]
 	fn receive_messages_delivery_proof_rejects_invalid_proof() {
 		run_test(|| {
@@ -1547,18 +1858,105 @@ mod tests {
 			let message2 = message(2, message_payload(0, u64::MAX / 2));
 			let message3 = message(3, message_payload(0, u64::MAX / 2));
 
-			assert_noop!(
+			// only two first messages fit into the declared weight
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				// this may cause overflow if source chain storage is invalid
+				Ok(vec![message1, message2, message3]).into(),
+				3,
+				Weight::MAX,
+			));
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 2);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_partially_dispatches_messages_if_dispatch_weight_is_not_enough() {
+		run_test(|| {
+			let proof: TestMessagesProof = Ok(vec![
+				message(1, REGULAR_PAYLOAD),
+				message(2, REGULAR_PAYLOAD),
+				message(3, REGULAR_PAYLOAD),
+			])
+			.into();
+
+			// declared weight is only enough to dispatch two messages
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof.clone(),
+				3,
+				REGULAR_PAYLOAD.declared_weight + REGULAR_PAYLOAD.declared_weight,
+			));
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 2);
+
+			// the same proof may be resubmitted to deliver the remaining message
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof,
+				3,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 3);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_resumes_dispatch_of_proof_that_exceeds_extrinsic_weight() {
+		run_test(|| {
+			let proof: TestMessagesProof = Ok(vec![
+				message(1, REGULAR_PAYLOAD),
+				message(2, REGULAR_PAYLOAD),
+				message(3, REGULAR_PAYLOAD),
+			])
+			.into();
+
+			// a single extrinsic may only dispatch two messages of the proof
+			let max_extrinsic_weight =
+				<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
+					&proof,
+					3,
+					REGULAR_PAYLOAD.declared_weight.saturating_mul(2),
+				);
+			MaxExtrinsicWeight::set(&Some(max_extrinsic_weight));
+
+			// the call, declaring weight of all messages, is still accepted by the `CheckWeight`
+			let receive_messages_proof = |proof: TestMessagesProof| {
+				let call = Call::<TestRuntime, ()>::receive_messages_proof {
+					relayer_id_at_bridged_chain: TEST_RELAYER_A,
+					proof: proof.clone(),
+					messages_count: 3,
+					dispatch_weight: REGULAR_PAYLOAD.declared_weight.saturating_mul(3),
+				};
+				let info = call.get_dispatch_info();
+				assert_eq!(info.weight, max_extrinsic_weight);
+				assert_ok!(frame_system::CheckWeight::<TestRuntime>::do_pre_dispatch(&info, 0));
+
 				Pallet::<TestRuntime, ()>::receive_messages_proof(
 					RuntimeOrigin::signed(1),
 					TEST_RELAYER_A,
-					// this may cause overflow if source chain storage is invalid
-					Ok(vec![message1, message2, message3]).into(),
+					proof,
 					3,
-					Weight::MAX,
-				),
-				Error::<TestRuntime, ()>::InsufficientDispatchWeight
+					REGULAR_PAYLOAD.declared_weight.saturating_mul(3),
+				)
+				.unwrap()
+				.actual_weight
+				.unwrap()
+			};
+
+			// two messages are delivered and relayer is refunded for delivery of the third one
+			let actual_weight = receive_messages_proof(proof.clone());
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 2);
+			let expected_weight = max_extrinsic_weight.saturating_sub(
+				<TestRuntime as Config>::WeightInfo::receive_messages_proof_messages_overhead(1),
 			);
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 0);
+			assert_eq!(actual_weight.ref_time(), expected_weight.ref_time());
+
+			// the same proof is resubmitted to deliver the remaining message
+			receive_messages_proof(proof);
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 3);
 		});
 	}
 
@@ -32,9 +32,12 @@ use bp_messages::{
 use bp_runtime::{messages::MessageDispatchResult, Size};
 use codec::{Decode, Encode};
 use frame_support::{
-	derive_impl, parameter_types,
+	derive_impl,
+	dispatch::DispatchClass,
+	parameter_types,
 	weights::{constants::RocksDbWeight, Weight},
 };
+use frame_system::limits::BlockWeights;
 use scale_info::TypeInfo;
 use sp_runtime::BuildStorage;
 use std::{
@@ -77,10 +80,30 @@ frame_support::construct_runtime! {
 
 pub type DbWeight = RocksDbWeight;
 
+parameter_types! {
+	/// Normal-class weight that a single extrinsic may use. Extrinsics are unlimited by default.
+	pub storage MaxExtrinsicWeight: Option<Weight> = None;
+	pub TestBlockWeights: BlockWeights = BlockWeights::builder()
+		.base_block(Weight::zero())
+		.for_class(DispatchClass::all(), |weights| {
+			weights.base_extrinsic = Weight::zero();
+		})
+		.for_class(DispatchClass::Normal, |weights| {
+			weights.max_extrinsic = MaxExtrinsicWeight::get();
+			weights.max_total = None;
+			weights.reserved = None;
+		})
+		.for_class(DispatchClass::Operational, |weights| {
+			weights.max_total = Some(Weight::MAX);
+		})
+		.build_or_panic();
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for TestRuntime {
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<Balance>;
+	type BlockWeights = TestBlockWeights;
 	type DbWeight = DbWeight;
 }
 
@@ -95,6 +118,7 @@ parameter_types! {
 	pub const MaxUnconfirmedMessagesAtInboundLane: u64 = 128;
 	pub const TestBridgedChainId: bp_runtime::ChainId = *b"test";
 	pub const ActiveOutboundLanes: &'static [LaneId] = &[TEST_LANE_ID, TEST_LANE_ID_2];
+	pub storage MaxDeliveryProofsPerRelayerPerBlock: Option<u32> = None;
 }
 
 /// weights of messages pallet calls we use in tests.
@@ -111,12 +135,14 @@ impl Config for TestRuntime {
 	type OutboundPayload = TestPayload;
 
 	type InboundPayload = TestPayload;
+	type MaxInboundMessageSize = frame_support::traits::ConstU32<MAX_INBOUND_MESSAGE_SIZE>;
 	type InboundRelayer = TestRelayer;
 	type DeliveryPayments = TestDeliveryPayments;
 
 	type TargetHeaderChain = TestTargetHeaderChain;
 	type DeliveryConfirmationPayments = TestDeliveryConfirmationPayments;
 	type OnMessagesDelivered = TestOnMessagesDelivered;
+	type MaxDeliveryProofsPerRelayerPerBlock = MaxDeliveryProofsPerRelayerPerBlock;
 
 	type SourceHeaderChain = TestSourceHeaderChain;
 	type MessageDispatch = TestMessageDispatch;
@@ -170,6 +196,9 @@ impl Size for TestPayload {
 /// Maximal outbound payload size.
 pub const MAX_OUTBOUND_PAYLOAD_SIZE: u32 = 4096;
 
+/// Maximal inbound message size.
+pub const MAX_INBOUND_MESSAGE_SIZE: u32 = 1024;
+
 /// Account that has balance to use in tests.
 pub const ENDOWED_ACCOUNT: AccountId = 0xDEAD;
 
@@ -427,6 +456,7 @@ pub const fn dispatch_result(
 	MessageDispatchResult {
 		unspent_weight: Weight::from_parts(unspent_weight, 0),
 		dispatch_level_result: (),
+		dispatch_error: None,
 	}
 }
 
@@ -238,6 +238,15 @@ pub mod pallet {
 			true
 		}
 
+		/// Returns the total reward that the given relayer may claim, over all lanes and all
+		/// rewards accounts.
+		///
+		/// Use [`Pallet::relayer_reward`] to get the reward for a single rewards account.
+		pub fn total_relayer_reward(relayer: &T::AccountId) -> T::Reward {
+			RelayerRewards::<T>::iter_prefix_values(relayer)
+				.fold(Zero::zero(), |total: T::Reward, reward| total.saturating_add(reward))
+		}
+
 		/// Slash and `deregister` relayer. This function slashes all staked balance.
 		///
 		/// It may fail inside, but error is swallowed and we only log it.
@@ -592,6 +601,43 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn total_relayer_reward_sums_rewards_at_all_lanes() {
+		run_test(|| {
+			let in_lane_1 = RewardsAccountParams::new(
+				LaneId([0, 0, 0, 1]),
+				*b"test",
+				RewardsAccountOwner::ThisChain,
+			);
+			let out_lane_1 = RewardsAccountParams::new(
+				LaneId([0, 0, 0, 1]),
+				*b"test",
+				RewardsAccountOwner::BridgedChain,
+			);
+			assert_eq!(Pallet::<TestRuntime>::total_relayer_reward(&REGULAR_RELAYER), 0);
+
+			Pallet::<TestRuntime>::register_relayer_reward(
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				&REGULAR_RELAYER,
+				100,
+			);
+			Pallet::<TestRuntime>::register_relayer_reward(in_lane_1, &REGULAR_RELAYER, 200);
+			Pallet::<TestRuntime>::register_relayer_reward(out_lane_1, &REGULAR_RELAYER, 300);
+			Pallet::<TestRuntime>::register_relayer_reward(in_lane_1, &FAILING_RELAYER, 400);
+
+			let per_lane_rewards = [TEST_REWARDS_ACCOUNT_PARAMS, in_lane_1, out_lane_1]
+				.into_iter()
+				.filter_map(|params| Pallet::<TestRuntime>::relayer_reward(REGULAR_RELAYER, params))
+				.sum::<Balance>();
+			assert_eq!(per_lane_rewards, 600);
+			assert_eq!(
+				Pallet::<TestRuntime>::total_relayer_reward(&REGULAR_RELAYER),
+				per_lane_rewards
+			);
+			assert_eq!(Pallet::<TestRuntime>::total_relayer_reward(&FAILING_RELAYER), 400);
+		});
+	}
+
 	#[test]
 	fn pay_reward_from_account_actually_pays_reward() {
 		type Balances = pallet_balances::Pallet<TestRuntime>;
@@ -45,6 +45,13 @@ pub trait Config<I: 'static>: crate::Config<I> {
 	}
 }
 
+/// Returns XCM program which encoded size is close to (but never exceeds) the given `size`.
+pub(crate) fn message_of_size(size: u32) -> Xcm<()> {
+	// every `ClearOrigin` is encoded using a single byte and we need at most 4 more bytes
+	// for the compact-encoded length of instructions vector
+	Xcm(sp_std::vec![ClearOrigin; size.saturating_sub(4) as usize])
+}
+
 benchmarks_instance_pallet! {
 	on_initialize_when_non_congested {
 		Bridge::<T, I>::put(BridgeState {
@@ -92,4 +99,67 @@ benchmarks_instance_pallet! {
 	verify {
 		assert!(Bridge::<T, I>::get().delivery_fee_factor > MINIMAL_DELIVERY_FEE_FACTOR);
 	}
+
+	validate_message {
+		let s in 1 .. T::HardMessageSizeLimit::get();
+
+		let dest = T::ensure_bridged_target_destination()?;
+		let xcm = message_of_size(s);
+	}: {
+		<crate::Pallet<T, I> as SendXcm>::validate(&mut Some(dest), &mut Some(xcm))
+			.expect("message is valid")
+	}
+
+	deliver_message {
+		let s in 1 .. T::HardMessageSizeLimit::get();
+
+		let dest = T::ensure_bridged_target_destination()?;
+		let xcm = message_of_size(s);
+		let (ticket, _) = <crate::Pallet<T, I> as SendXcm>::validate(&mut Some(dest), &mut Some(xcm))
+			.map_err(|_| BenchmarkError::Stop("message is not valid"))?;
+
+		// make local queue congested, because it means additional db write
+		T::make_congested();
+	}: {
+		<crate::Pallet<T, I> as SendXcm>::deliver(ticket).expect("message is delivered")
+	}
+	verify {
+		assert!(Bridge::<T, I>::get().delivery_fee_factor > MINIMAL_DELIVERY_FEE_FACTOR);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use codec::Encode;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn message_of_size_is_close_to_requested_size() {
+		for size in [1, 4, 5, 1024, HardMessageSizeLimit::get()] {
+			let encoded_size = message_of_size(size).encoded_size() as u32;
+			assert!(encoded_size <= size);
+			assert!(encoded_size + 4 >= size);
+		}
+	}
+
+	#[test]
+	fn validate_and_deliver_benchmarks_work_with_small_and_near_limit_messages() {
+		// benchmark tests are executed for the lowest (single byte) and highest (exactly
+		// `HardMessageSizeLimit`) values of the message size component
+		run_test(|| assert_ok!(Pallet::<TestRuntime, ()>::test_benchmark_validate_message()));
+		run_test(|| assert_ok!(Pallet::<TestRuntime, ()>::test_benchmark_deliver_message()));
+	}
+
+	#[test]
+	fn validate_and_deliver_benchmarks_work_with_smaller_message_size_limit() {
+		run_test(|| {
+			HardMessageSizeLimit::set(&1024);
+			assert_ok!(Pallet::<TestRuntime, ()>::test_benchmark_validate_message());
+			assert_ok!(Pallet::<TestRuntime, ()>::test_benchmark_deliver_message());
+		});
+	}
 }
@@ -30,37 +30,126 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use bp_runtime::{BasicOperatingMode, OperatingMode};
 use bp_xcm_bridge_hub_router::{
 	BridgeState, XcmChannelStatusProvider, MINIMAL_DELIVERY_FEE_FACTOR,
 };
-use codec::Encode;
-use frame_support::traits::Get;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch_context::with_context, traits::Get};
+use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{FixedPointNumber, FixedU128, Saturating};
-use sp_std::vec::Vec;
+use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128, Permill, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
 use xcm::prelude::*;
-use xcm_builder::{ExporterFor, InspectMessageQueues, SovereignPaidRemoteExporter};
+use xcm_builder::{
+	ensure_is_remote, ExporterFor, InspectMessageQueues, SovereignPaidRemoteExporter,
+};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
 pub mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 mod mock;
 
-/// The factor that is used to increase current message fee factor when bridge experiencing
-/// some lags.
-const EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(105, 100); // 1.05
-/// The factor that is used to increase current message fee factor for every sent kilobyte.
-const MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 1000); // 0.001
+/// The default value of the `Config::ExponentialFeeBase`.
+pub const EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(105, 100); // 1.05
+/// The default value of the `Config::MessageSizeFeeBase`.
+pub const MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 1000); // 0.001
+
+/// The curve, which maps the size of the sent message to the increase of the delivery fee factor.
+#[derive(Clone, Debug, Decode, Default, Encode, PartialEq, Eq)]
+pub enum MessageSizeFeeCurve {
+	/// The factor is increased by the message size fee base for every sent kilobyte.
+	#[default]
+	Linear,
+	/// The factor is increased by the message size fee base for every squared sent kilobyte, so
+	/// that the large messages, which consume disproportionately more of the bridge capacity,
+	/// cost more.
+	Quadratic,
+	/// The factor is increased by the value of the table entry with the largest message size (in
+	/// kilobytes) not exceeding the size of the sent message. Entries must be sorted by the size.
+	Piecewise(Vec<(u32, FixedU128)>),
+}
+
+impl MessageSizeFeeCurve {
+	/// Returns the increase of the delivery fee factor for the message of given size.
+	pub fn message_size_factor(
+		&self,
+		message_size: u32,
+		message_size_fee_base: FixedU128,
+	) -> FixedU128 {
+		let size_in_kib = message_size.saturating_div(1024);
+		match self {
+			Self::Linear =>
+				FixedU128::from_u32(size_in_kib).saturating_mul(message_size_fee_base),
+			Self::Quadratic => FixedU128::from_u32(size_in_kib)
+				.saturating_mul(FixedU128::from_u32(size_in_kib))
+				.saturating_mul(message_size_fee_base),
+			Self::Piecewise(table) => table
+				.iter()
+				.take_while(|(min_size_in_kib, _)| *min_size_in_kib <= size_in_kib)
+				.last()
+				.map_or(FixedU128::from_u32(0), |(_, factor)| *factor),
+		}
+	}
+}
+
+/// Routability of the destination, resolved by the router during the current dispatch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DestinationRoutability {
+	/// The destination is not routed by this router.
+	NotRoutable,
+	/// The destination is routed by this router and it has given XCM version.
+	Routable(XcmVersion),
+}
+
+/// Routability and XCM versions of destinations, resolved during the current dispatch.
+///
+/// The cache is kept in the dispatch context, so it never ends up in the storage and every
+/// destination is resolved again by the next dispatch.
+struct DestinationCache<T, I>(Vec<(Location, DestinationRoutability)>, PhantomData<(T, I)>);
+
+impl<T, I> Default for DestinationCache<T, I> {
+	fn default() -> Self {
+		Self(Vec::new(), PhantomData)
+	}
+}
+
+/// The reason, why the message has been dropped by the router.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+pub enum DropReason {
+	/// The message is larger than the `Config::HardMessageSizeLimit`.
+	ExceedsMaxMessageSize,
+	/// The XCM version of the destination is unknown or the message can't be converted to it.
+	UnsupportedVersion,
+}
+
+/// Identifier of the bridge in the `Config::PerBridgeFeeFactors` mode: the bridged network and the
+/// parachain at this network (if any) the messages are routed to.
+pub type BridgeId = (NetworkId, Option<u32>);
+
+/// Delivery fee factor of the individual bridge in the `Config::PerBridgeFeeFactors` mode.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct BridgeFeeFactor<BlockNumber> {
+	/// The delivery fee factor of the bridge.
+	pub delivery_fee_factor: FixedU128,
+	/// Number of the block, where the factor has been changed last time. If the factor is
+	/// minimal, it is the block where the bridge has become idle.
+	pub updated_at: BlockNumber,
+}
 
-/// Maximal size of the XCM message that may be sent over bridge.
+/// The default value of the `Config::HardMessageSizeLimit`.
 ///
 /// This should be less than the maximal size, allowed by the messages pallet, because
 /// the message itself is wrapped in other structs and is double encoded.
 pub const HARD_MESSAGE_SIZE_LIMIT: u32 = 32 * 1024;
 
+/// Maximal number of destinations, which routability is cached during a single dispatch.
+pub const DESTINATION_CACHE_CAPACITY: u32 = 8;
+
 /// The target that will be used when publishing logs related to this pallet.
 ///
 /// This doesn't match the pattern used by other bridge pallets (`runtime::bridge-*`). But this
@@ -69,14 +158,32 @@ pub const HARD_MESSAGE_SIZE_LIMIT: u32 = 32 * 1024;
 /// bridge hub parachain.
 pub const LOG_TARGET: &str = "xcm::bridge-hub-router";
 
+/// Destination of the message, routed over the bridge, as reported by the
+/// [`Pallet::inspect_routed_destination`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoutedDestination {
+	/// Location of the sibling/child bridge hub, which is passed to the
+	/// `Config::ToBridgeHubSender`.
+	pub original: Location,
+	/// Destination, as left by the `Config::ToBridgeHubSender` after validation. It is `None`
+	/// if the sender has consumed the destination.
+	pub rewritten: Option<Location>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Benchmarks results from runtime we're plugged into.
 		type WeightInfo: WeightInfo;
 
@@ -95,6 +202,8 @@ pub mod pallet {
 
 		/// Origin of the sibling bridge hub that is allowed to report bridge status.
 		type BridgeHubOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin that is allowed to halt and resume the router.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Actual message sender (`HRMP` or `DMP`) to the sibling bridge hub location.
 		type ToBridgeHubSender: SendXcm + InspectMessageQueues;
 		/// Underlying channel with the sibling bridge hub. It must match the channel, used
@@ -103,11 +212,78 @@ pub mod pallet {
 
 		/// Additional fee that is paid for every byte of the outbound message.
 		type ByteFee: Get<u128>;
+		/// If `true`, the delivery fee factor is applied to the sum of the base fee and the
+		/// per-byte fee. If `false`, only the base fee is scaled by the factor and the per-byte
+		/// fee is added to the result as is.
+		type ScaleByteFeeByFactor: Get<bool>;
 		/// Asset that is used to paid bridge fee.
 		type FeeAsset: Get<AssetId>;
+		/// Additional fees that are paid for every byte of the outbound message in other assets.
+		///
+		/// Unlike the `ByteFee`, which is paid in the `FeeAsset` at the sibling/child bridge hub,
+		/// these fees are added to the delivery cost, returned by the router. They are scaled by
+		/// the delivery fee factor in the same way as the `ByteFee` is.
+		type AdditionalByteFees: Get<Vec<(AssetId, u128)>>;
+		/// Maximal number of distinct assets in the delivery cost, returned by the router. Fees,
+		/// paid in the same asset, are merged into a single entry. If the cost would contain more
+		/// assets, the message is rejected with `SendError::Fees` and no fee is reported for it
+		/// by the runtime API.
+		#[pallet::constant]
+		type MaxFeeAssets: Get<u32>;
+		/// Maximal size of the XCM message that may be sent over bridge.
+		///
+		/// It should be less than the maximal size, allowed by the messages pallet at the
+		/// bridge hub, because the message itself is wrapped in other structs and is double
+		/// encoded. The default is [`HARD_MESSAGE_SIZE_LIMIT`].
+		#[pallet::constant]
+		type HardMessageSizeLimit: Get<u32>;
+		/// Delivery fee factor above which the messages are considered to be sent during severe
+		/// congestion. Every such message increments the `FeeFactorAlerts` counter. The
+		/// `DeliveryFeeFactorAlert` event is emitted when the factor crosses the threshold.
+		type FeeFactorAlertThreshold: Get<FixedU128>;
+		/// Utilization of the channel with the sibling bridge hub, above which the channel is
+		/// considered congested. The delivery fee factor increase is scaled by the utilization
+		/// above this threshold, so the factor grows more steeply for more utilized channels.
+		///
+		/// With the zero threshold and the default `XcmChannelStatusProvider::utilization`, the
+		/// factor is increased in full whenever the channel is congested.
+		#[pallet::constant]
+		type CongestionUtilizationThreshold: Get<Permill>;
+		/// Maximal factor by which the delivery fee factor may be increased when a single message
+		/// is sent. It caps the increase caused by large messages. Must not be less than one.
+		#[pallet::constant]
+		type MaxFeeFactorIncreasePerSend: Get<FixedU128>;
+		/// Maximal value of the delivery fee factor. The factor is never increased above this
+		/// value, so that it may decay in reasonable time once the congestion is resolved.
+		#[pallet::constant]
+		type MaximumFeeFactor: Get<FixedU128>;
+		/// The factor by which the delivery fee factor is increased for every message, sent over
+		/// the congested bridge, and decreased for every block, when the bridge is not congested.
+		/// Must be greater than one. The default is [`EXPONENTIAL_FEE_BASE`].
+		#[pallet::constant]
+		type ExponentialFeeBase: Get<FixedU128>;
+		/// The increase of the delivery fee factor for every kilobyte of the message, sent over
+		/// the congested bridge. The default is [`MESSAGE_SIZE_FEE_BASE`].
+		#[pallet::constant]
+		type MessageSizeFeeBase: Get<FixedU128>;
+		/// The curve, which maps the size of the sent message to the increase of the delivery fee
+		/// factor. Use `()` for the default linear curve.
+		type MessageSizeFeeCurve: Get<MessageSizeFeeCurve>;
+		/// If `true`, every bridge (identified by the [`BridgeId`]) has its own delivery fee
+		/// factor, so the fees of messages over one bridge are not affected by the messages sent
+		/// over other bridges. Use `()` to share the single factor between all bridges.
+		type PerBridgeFeeFactors: Get<bool>;
+		/// Maximal number of bridges, which delivery fee factors are tracked in the
+		/// `Config::PerBridgeFeeFactors` mode. Once it is reached, the factor of the bridge that
+		/// has been idle (at the minimal factor) for the longest time is evicted to track the
+		/// factor of the new bridge. It bounds the work, done by the `on_initialize`, so it must
+		/// be at least one.
+		#[pallet::constant]
+		type MaxTrackedBridges: Get<u32>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
@@ -118,35 +294,41 @@ pub mod pallet {
 			// that is definitely congestion. If inbound is suspended, then we are not able to
 			// receive the "report_bridge_status" signal (that maybe sent by the bridge hub).
 
-			// if the channel with sibling/child bridge hub is suspended, we don't change
-			// anything
-			if T::WithBridgeHubChannel::is_congested() {
+			// if the channel with sibling/child bridge hub is utilized above the threshold (e.g.
+			// it is suspended), we don't change anything - the same threshold is used to
+			// increase the fee factor
+			let utilization = T::WithBridgeHubChannel::utilization(None);
+			if utilization > T::CongestionUtilizationThreshold::get() {
 				return T::WeightInfo::on_initialize_when_congested()
 			}
 
 			// if bridge has reported congestion, we don't change anything
-			let mut bridge = Self::bridge();
-			if bridge.is_congested {
+			if Self::bridge().is_congested {
 				return T::WeightInfo::on_initialize_when_congested()
 			}
 
-			// if fee factor is already minimal, we don't change anything
-			if bridge.delivery_fee_factor == MINIMAL_DELIVERY_FEE_FACTOR {
-				return T::WeightInfo::on_initialize_when_congested()
+			// every tracked factor is read and written back by the update and then read again
+			// to find the highest one
+			let tracked = if T::PerBridgeFeeFactors::get() {
+				DeliveryFeeFactors::<T, I>::count() as u64
+			} else {
+				0
+			};
+			let tracked_weight = T::DbWeight::get().reads_writes(2 * tracked, tracked);
+
+			// fee factors, which are already minimal, are not changed
+			let exponential_fee_base = T::ExponentialFeeBase::get();
+			let decreased = Self::update_delivery_fee_factors(|factor| {
+				MINIMAL_DELIVERY_FEE_FACTOR.max(factor / exponential_fee_base)
+			});
+			if decreased == 0 {
+				return T::WeightInfo::on_initialize_when_congested().saturating_add(tracked_weight)
 			}
 
-			let previous_factor = bridge.delivery_fee_factor;
-			bridge.delivery_fee_factor =
-				MINIMAL_DELIVERY_FEE_FACTOR.max(bridge.delivery_fee_factor / EXPONENTIAL_FEE_BASE);
-			log::info!(
-				target: LOG_TARGET,
-				"Bridge queue is uncongested. Decreased fee factor from {} to {}",
-				previous_factor,
-				bridge.delivery_fee_factor,
-			);
-
-			Bridge::<T, I>::put(bridge);
+			Self::update_fee_factor_alert(Self::highest_delivery_fee_factor());
 			T::WeightInfo::on_initialize_when_non_congested()
+				.saturating_mul(decreased.into())
+				.saturating_add(tracked_weight)
 		}
 	}
 
@@ -176,6 +358,67 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Halt or resume sending messages over the bridge.
+		///
+		/// While the router is halted, all messages to the bridged network are rejected. The
+		/// delivery fee factor keeps decreasing as usual.
+		///
+		/// May only be called by the `AdminOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		pub fn set_operating_mode(
+			origin: OriginFor<T>,
+			operating_mode: BasicOperatingMode,
+		) -> DispatchResult {
+			let _ = T::AdminOrigin::ensure_origin(origin)?;
+
+			log::info!(target: LOG_TARGET, "Setting operating mode to {:?}.", operating_mode);
+
+			PalletOperatingMode::<T, I>::put(operating_mode);
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// The delivery fee factor has risen above the `Config::FeeFactorAlertThreshold`.
+		DeliveryFeeFactorAlert {
+			/// The delivery fee factor.
+			value: FixedU128,
+		},
+		/// The delivery fee factor has fallen back to the `Config::FeeFactorAlertThreshold`.
+		DeliveryFeeFactorAlertCleared {
+			/// The delivery fee factor.
+			value: FixedU128,
+		},
+		/// The delivery fee factor has been reset by the [`migration::MigrateV0ToV1`] on the
+		/// runtime upgrade.
+		DeliveryFeeFactorDecreased {
+			/// The new value of the delivery fee factor.
+			new_value: FixedU128,
+		},
+		/// The delivery fee factor has been increased, because the message has been sent over
+		/// the congested bridge.
+		DeliveryFeeFactorIncreased {
+			/// The bridge, which delivery fee factor has been increased.
+			bridge_id: BridgeId,
+			/// The new value of the delivery fee factor.
+			new_value: FixedU128,
+		},
+		/// The message has been dropped by the router.
+		///
+		/// This event is best-effort. It is deposited when the message is validated, so it is
+		/// reverted along with other changes if the caller rolls back the failed validation (e.g.
+		/// when the `send` extrinsic fails) and it may be deposited by dry-runs that never
+		/// commit the changes. Use it for diagnostics, not for accounting.
+		MessageDropped {
+			/// The reason, why the message has been dropped.
+			reason: DropReason,
+			/// The size of the dropped message.
+			message_size: u32,
+		},
 	}
 
 	/// Bridge that we are using.
@@ -189,41 +432,488 @@ pub mod pallet {
 	#[pallet::getter(fn bridge)]
 	pub type Bridge<T: Config<I>, I: 'static = ()> = StorageValue<_, BridgeState, ValueQuery>;
 
+	/// The current operating mode of the router.
+	///
+	/// If halted, the router rejects all messages to the bridged network.
+	#[pallet::storage]
+	#[pallet::getter(fn operating_mode)]
+	pub type PalletOperatingMode<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BasicOperatingMode, ValueQuery>;
+
+	/// The number of messages that have been sent while the delivery fee factor has been above
+	/// the `Config::FeeFactorAlertThreshold`.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_factor_alerts)]
+	pub type FeeFactorAlerts<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+	/// Whether the delivery fee factor is currently above the `Config::FeeFactorAlertThreshold`
+	/// and the `DeliveryFeeFactorAlert` event has been emitted.
+	#[pallet::storage]
+	pub type FeeFactorAlertRaised<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, bool, ValueQuery>;
+
+	/// Delivery fee factors of individual bridges, used instead of the factor in the `Bridge` in
+	/// the `Config::PerBridgeFeeFactors` mode. Bridges, which factors have decreased to the
+	/// minimal value, are kept idle until they are evicted to track the factor of the new bridge.
+	/// There are at most `Config::MaxTrackedBridges` entries.
+	#[pallet::storage]
+	pub type DeliveryFeeFactors<T: Config<I>, I: 'static = ()> = CountedStorageMap<
+		_,
+		Blake2_128Concat,
+		BridgeId,
+		BridgeFeeFactor<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Called when new message is sent (queued to local outbound XCM queue) over the bridge.
-		pub(crate) fn on_message_sent_to_bridge(message_size: u32) {
-			log::trace!(
+		/// Returns true if the router is halted and rejects all outbound messages.
+		pub fn is_halted() -> bool {
+			Self::operating_mode().is_halted()
+		}
+
+		/// Returns weight of routing message of given size over the bridge, using our `SendXcm`
+		/// implementation. It covers both `validate` and `deliver` calls and shall be accounted
+		/// by the code that is invoking the router.
+		pub fn send_message_weight(message_size: u32) -> Weight {
+			T::WeightInfo::validate_message(message_size)
+				.saturating_add(T::WeightInfo::deliver_message(message_size))
+		}
+
+		/// Resolves the sibling/child bridge hub, which would carry the message to the given
+		/// remote destination, and validates the message using the `Config::ToBridgeHubSender`.
+		/// Returns both the bridge hub location and the destination, as it is left by the sender.
+		///
+		/// This is a diagnostic helper - the message is never enqueued.
+		pub fn inspect_routed_destination(
+			dest: Location,
+			xcm: Xcm<()>,
+		) -> Result<RoutedDestination, SendError> {
+			let (network, remote_location) = ensure_is_remote(T::UniversalLocation::get(), dest)
+				.map_err(|_| SendError::NotApplicable)?;
+			let (original, _) = Self::exporter_for(&network, &remote_location, &xcm)
+				.ok_or(SendError::NotApplicable)?;
+
+			let mut rewritten = Some(original.clone());
+			let _ = T::ToBridgeHubSender::validate(&mut rewritten, &mut Some(xcm))?;
+			Ok(RoutedDestination { original, rewritten })
+		}
+
+		/// Returns the identifier of the bridge, which carries messages to the given remote
+		/// location at the given bridged network.
+		pub fn bridge_id(network: &NetworkId, remote_location: &InteriorLocation) -> BridgeId {
+			let para_id = match remote_location.first() {
+				Some(Parachain(para_id)) => Some(*para_id),
+				_ => None,
+			};
+			(*network, para_id)
+		}
+
+		/// Returns the delivery fee factor of the given bridge.
+		pub fn delivery_fee_factor(bridge_id: &BridgeId) -> FixedU128 {
+			if T::PerBridgeFeeFactors::get() {
+				DeliveryFeeFactors::<T, I>::get(bridge_id)
+					.map_or(MINIMAL_DELIVERY_FEE_FACTOR, |factor| factor.delivery_fee_factor)
+			} else {
+				Self::bridge().delivery_fee_factor
+			}
+		}
+
+		/// Sets the delivery fee factor of the given bridge.
+		fn set_delivery_fee_factor(bridge_id: &BridgeId, delivery_fee_factor: FixedU128) {
+			if !T::PerBridgeFeeFactors::get() {
+				Bridge::<T, I>::mutate(|bridge| bridge.delivery_fee_factor = delivery_fee_factor);
+			} else {
+				if !DeliveryFeeFactors::<T, I>::contains_key(bridge_id) &&
+					DeliveryFeeFactors::<T, I>::count() >= T::MaxTrackedBridges::get()
+				{
+					Self::evict_stale_bridge();
+				}
+				DeliveryFeeFactors::<T, I>::insert(
+					bridge_id,
+					BridgeFeeFactor {
+						delivery_fee_factor,
+						updated_at: frame_system::Pallet::<T>::block_number(),
+					},
+				);
+			}
+		}
+
+		/// Stops tracking the delivery fee factor of the bridge that has been idle (at the
+		/// minimal factor) for the longest time. If all tracked bridges are congested, the
+		/// factor of the least congested one is evicted, so it drops to the minimal value.
+		fn evict_stale_bridge() {
+			let Some((bridge_id, factor)) = DeliveryFeeFactors::<T, I>::iter()
+				.min_by_key(|(_, factor)| (factor.delivery_fee_factor, factor.updated_at))
+			else {
+				return
+			};
+
+			log::info!(
 				target: LOG_TARGET,
-				"on_message_sent_to_bridge - message_size: {message_size:?}",
+				"Evicted fee factor {} of bridge {:?} (updated at {:?}) to track the new bridge",
+				factor.delivery_fee_factor,
+				bridge_id,
+				factor.updated_at,
 			);
-			let _ = Bridge::<T, I>::try_mutate(|bridge| {
-				let is_channel_with_bridge_hub_congested = T::WithBridgeHubChannel::is_congested();
-				let is_bridge_congested = bridge.is_congested;
+			DeliveryFeeFactors::<T, I>::remove(bridge_id);
+		}
 
-				// if outbound queue is not congested AND bridge has not reported congestion, do
-				// nothing
-				if !is_channel_with_bridge_hub_congested && !is_bridge_congested {
-					return Err(())
-				}
+		/// Returns the highest delivery fee factor of all bridges.
+		pub fn highest_delivery_fee_factor() -> FixedU128 {
+			if T::PerBridgeFeeFactors::get() {
+				DeliveryFeeFactors::<T, I>::iter_values()
+					.map(|factor| factor.delivery_fee_factor)
+					.fold(MINIMAL_DELIVERY_FEE_FACTOR, Ord::max)
+			} else {
+				Self::bridge().delivery_fee_factor
+			}
+		}
 
-				// ok - we need to increase the fee factor, let's do that
-				let message_size_factor = FixedU128::from_u32(message_size.saturating_div(1024))
-					.saturating_mul(MESSAGE_SIZE_FEE_BASE);
-				let total_factor = EXPONENTIAL_FEE_BASE.saturating_add(message_size_factor);
+		/// Applies the `update` to the delivery fee factors of all bridges. Returns the number of
+		/// changed factors.
+		pub(crate) fn update_delivery_fee_factors(
+			mut update: impl FnMut(FixedU128) -> FixedU128,
+		) -> u32 {
+			if !T::PerBridgeFeeFactors::get() {
+				let mut bridge = Self::bridge();
 				let previous_factor = bridge.delivery_fee_factor;
-				bridge.delivery_fee_factor =
-					bridge.delivery_fee_factor.saturating_mul(total_factor);
+				bridge.delivery_fee_factor = update(previous_factor);
+				if bridge.delivery_fee_factor == previous_factor {
+					return 0
+				}
 
 				log::info!(
 					target: LOG_TARGET,
-					"Bridge channel is congested. Increased fee factor from {} to {}",
+					"Updated fee factor from {} to {}",
 					previous_factor,
 					bridge.delivery_fee_factor,
 				);
+				Bridge::<T, I>::put(bridge);
+				return 1
+			}
+
+			let mut updated = 0;
+			let block_number = frame_system::Pallet::<T>::block_number();
+			DeliveryFeeFactors::<T, I>::translate(
+				|bridge_id, previous: BridgeFeeFactor<BlockNumberFor<T>>| {
+					let delivery_fee_factor = update(previous.delivery_fee_factor);
+					if delivery_fee_factor == previous.delivery_fee_factor {
+						return Some(previous)
+					}
+
+					log::info!(
+						target: LOG_TARGET,
+						"Updated fee factor of bridge {:?} from {} to {}",
+						bridge_id,
+						previous.delivery_fee_factor,
+						delivery_fee_factor,
+					);
+					updated += 1;
+					Some(BridgeFeeFactor { delivery_fee_factor, updated_at: block_number })
+				},
+			);
+			updated
+		}
+
+		/// Returns the location of the sibling/child bridge hub and the bridge fee for sending
+		/// the message of given size to the given remote location at the bridged network.
+		fn bridge_fee(
+			network: &NetworkId,
+			remote_location: &InteriorLocation,
+			message: &Xcm<()>,
+			message_size: usize,
+		) -> Option<(Location, Option<Asset>)> {
+			log::trace!(
+				target: LOG_TARGET,
+				"bridge_fee - network: {network:?}, remote_location: {remote_location:?}, msg: {message:?}",
+			);
+			// ensure that the message is sent to the expected bridged network (if specified).
+			if let Some(bridged_network) = T::BridgedNetworkId::get() {
+				if *network != bridged_network {
+					log::trace!(
+						target: LOG_TARGET,
+						"Router with bridged_network_id {bridged_network:?} does not support bridging to network {network:?}!",
+					);
+					return None
+				}
+			}
+
+			// ensure that the message is sent to the expected bridged network and location.
+			let Some((bridge_hub_location, maybe_payment)) =
+				T::Bridges::exporter_for(network, remote_location, message)
+			else {
+				log::trace!(
+					target: LOG_TARGET,
+					"Router with bridged_network_id {:?} does not support bridging to network {:?} and remote_location {:?}!",
+					T::BridgedNetworkId::get(),
+					network,
+					remote_location,
+				);
+				return None
+			};
+
+			// take `base_fee` from `T::Brides`, but it has to be the same `T::FeeAsset`
+			let base_fee = match maybe_payment {
+				Some(payment) => match payment {
+					Asset { fun: Fungible(amount), id } if id.eq(&T::FeeAsset::get()) => amount,
+					invalid_asset => {
+						log::error!(
+							target: LOG_TARGET,
+							"Router with bridged_network_id {:?} is configured for `T::FeeAsset` {:?} which is not \
+							compatible with {:?} for bridge_hub_location: {:?} for bridging to {:?}/{:?}!",
+							T::BridgedNetworkId::get(),
+							T::FeeAsset::get(),
+							invalid_asset,
+							bridge_hub_location,
+							network,
+							remote_location,
+						);
+						return None
+					},
+				},
+				None => 0,
+			};
+
+			// compute fee amount. Keep in mind that this is only the bridge fee. The fee for
+			// sending message from this chain to child/sibling bridge hub is determined by the
+			// `Config::ToBridgeHubSender`
+			let message_fee = (message_size as u128).saturating_mul(T::ByteFee::get());
+			let fee_factor = Self::delivery_fee_factor(&Self::bridge_id(network, remote_location));
+			let fee = if T::ScaleByteFeeByFactor::get() {
+				fee_factor.saturating_mul_int(base_fee.saturating_add(message_fee))
+			} else {
+				fee_factor.saturating_mul_int(base_fee).saturating_add(message_fee)
+			};
+
+			let fee = if fee > 0 { Some((T::FeeAsset::get(), fee).into()) } else { None };
+
+			log::info!(
+				target: LOG_TARGET,
+				"Validate send message to {:?} ({} bytes) over bridge. Computed bridge fee {:?} using fee factor {}",
+				(network, remote_location),
+				message_size,
+				fee,
+				fee_factor
+			);
+
+			Some((bridge_hub_location, fee))
+		}
+
+		/// Returns the additional byte fees (if any) for sending the message of given size over
+		/// the given bridge.
+		fn additional_byte_fees(bridge_id: &BridgeId, message_size: u32) -> Vec<Asset> {
+			let fee_factor = Self::delivery_fee_factor(bridge_id);
+			T::AdditionalByteFees::get()
+				.into_iter()
+				.filter_map(|(asset_id, per_byte_fee)| {
+					let fee = (message_size as u128).saturating_mul(per_byte_fee);
+					let fee = if T::ScaleByteFeeByFactor::get() {
+						fee_factor.saturating_mul_int(fee)
+					} else {
+						fee
+					};
+					(fee > 0).then(|| (asset_id, fee).into())
+				})
+				.collect()
+		}
+
+		/// Adds the additional byte fees (if any) for sending the message of given size over
+		/// the given bridge to the given fees. Returns `None` if the resulting fees would consist
+		/// of more than `Config::MaxFeeAssets` assets.
+		fn with_additional_byte_fees(
+			mut fees: Assets,
+			bridge_id: &BridgeId,
+			message_size: u32,
+		) -> Option<Assets> {
+			for fee in Self::additional_byte_fees(bridge_id, message_size) {
+				fees.push(fee);
+			}
+			if fees.len() > T::MaxFeeAssets::get() as usize {
+				log::trace!(
+					target: LOG_TARGET,
+					"too many fee assets: {} > {}",
+					fees.len(),
+					T::MaxFeeAssets::get(),
+				);
+				return None
+			}
+			Some(fees)
+		}
+
+		/// Returns the fees, charged by the router for sending the message of given size to the
+		/// given destination, or `None` if the message may not be routed there or the fees would
+		/// consist of more than `Config::MaxFeeAssets` assets. The fee for
+		/// delivering the message to the sibling/child bridge hub is not included.
+		///
+		/// Unlike the `validate` of our `SendXcm` implementation, it never changes the storage.
+		pub fn calculate_fee(dest: Location, message_size: u32) -> Option<Assets> {
+			if Self::is_halted() || message_size > T::HardMessageSizeLimit::get() {
+				return None
+			}
+
+			let (network, remote_location) =
+				ensure_is_remote(T::UniversalLocation::get(), dest).ok()?;
+			let (_, bridge_fee) = Self::bridge_fee(
+				&network,
+				&remote_location,
+				&Xcm::new(),
+				message_size as usize,
+			)?;
+
+			let bridge_id = Self::bridge_id(&network, &remote_location);
+			let mut fees = Assets::new();
+			if let Some(bridge_fee) = bridge_fee {
+				fees.push(bridge_fee);
+			}
+			Self::with_additional_byte_fees(fees, &bridge_id, message_size)
+		}
+
+		/// Returns XCM version of the routable destination.
+		///
+		/// The version is resolved using `Config::DestinationVersion` once per dispatch and then
+		/// it is served from the `DestinationCache`.
+		pub(crate) fn destination_version(dest: &Location) -> Option<XcmVersion> {
+			if let Some(DestinationRoutability::Routable(version)) = Self::cached_routability(dest)
+			{
+				return Some(version)
+			}
+
+			let version = T::DestinationVersion::get_version_for(dest)?;
+			Self::cache_routability(dest, DestinationRoutability::Routable(version));
+			Some(version)
+		}
 
-				Ok(())
+		/// Returns routability of the destination, if it has been resolved in the current
+		/// dispatch.
+		pub(crate) fn cached_routability(dest: &Location) -> Option<DestinationRoutability> {
+			with_context::<DestinationCache<T, I>, _>(|cache| {
+				cache.get().and_then(|cache| {
+					cache
+						.0
+						.iter()
+						.find(|(location, _)| location == dest)
+						.map(|(_, routability)| *routability)
+				})
+			})
+			.flatten()
+		}
+
+		/// Remembers routability of the destination until the end of the current dispatch.
+		/// Nothing is remembered outside of the dispatch context.
+		fn cache_routability(dest: &Location, routability: DestinationRoutability) {
+			with_context::<DestinationCache<T, I>, _>(|cache| {
+				let cache = &mut cache.or_default().0;
+				// if the cache is full, we simply don't cache the routability
+				if cache.len() < DESTINATION_CACHE_CAPACITY as usize {
+					cache.push((dest.clone(), routability));
+				}
 			});
 		}
+
+		/// Emits the `DeliveryFeeFactorAlert` event when the delivery fee factor rises above the
+		/// `Config::FeeFactorAlertThreshold` and the `DeliveryFeeFactorAlertCleared` event when it
+		/// falls back. Nothing is emitted while the factor stays on the same side of the threshold.
+		pub(crate) fn update_fee_factor_alert(delivery_fee_factor: FixedU128) {
+			let is_above_threshold = delivery_fee_factor > T::FeeFactorAlertThreshold::get();
+			if is_above_threshold == FeeFactorAlertRaised::<T, I>::get() {
+				return
+			}
+
+			FeeFactorAlertRaised::<T, I>::put(is_above_threshold);
+			if is_above_threshold {
+				Self::deposit_event(Event::DeliveryFeeFactorAlert { value: delivery_fee_factor });
+			} else {
+				Self::deposit_event(Event::DeliveryFeeFactorAlertCleared {
+					value: delivery_fee_factor,
+				});
+			}
+		}
+
+		/// Returns the congestion of the channel with the sibling bridge hub, which is used to
+		/// deliver messages to the given destination. It is the channel utilization above the
+		/// `CongestionUtilizationThreshold`, relative to the remaining capacity of the channel.
+		fn channel_with_bridge_hub_congestion(dest: &Location) -> FixedU128 {
+			let utilization = T::WithBridgeHubChannel::utilization(Some(dest)).deconstruct();
+			let threshold = T::CongestionUtilizationThreshold::get().deconstruct();
+			if utilization <= threshold {
+				return FixedU128::zero()
+			}
+
+			let capacity = Permill::one().deconstruct() - threshold;
+			Permill::from_rational(utilization - threshold, capacity).into()
+		}
+
+		/// Called when new message is sent (queued to local outbound XCM queue) over the bridge.
+		pub(crate) fn on_message_sent_to_bridge(
+			bridge_id: &BridgeId,
+			dest: &Location,
+			message_size: u32,
+		) {
+			log::trace!(
+				target: LOG_TARGET,
+				"on_message_sent_to_bridge - bridge_id: {:?}, dest: {:?}, message_size: {:?}",
+				bridge_id,
+				dest,
+				message_size,
+			);
+			let channel_congestion = Self::channel_with_bridge_hub_congestion(dest);
+			let is_bridge_congested = Self::bridge().is_congested;
+
+			// if outbound queue is not congested AND bridge has not reported congestion, do
+			// nothing
+			if channel_congestion.is_zero() && !is_bridge_congested {
+				return
+			}
+
+			// ok - we need to increase the fee factor, let's do that. The congested bridge
+			// increases it in full, otherwise the increase is scaled by the channel congestion
+			let congestion =
+				if is_bridge_congested { FixedU128::from_u32(1) } else { channel_congestion };
+			let message_size_factor = T::MessageSizeFeeCurve::get()
+				.message_size_factor(message_size, T::MessageSizeFeeBase::get());
+			let factor_increase = T::ExponentialFeeBase::get()
+				.saturating_add(message_size_factor)
+				.saturating_sub(FixedU128::from_u32(1))
+				.saturating_mul(congestion);
+			let total_factor = FixedU128::from_u32(1)
+				.saturating_add(factor_increase)
+				.min(T::MaxFeeFactorIncreasePerSend::get());
+			let previous_factor = Self::delivery_fee_factor(bridge_id);
+			let delivery_fee_factor =
+				previous_factor.saturating_mul(total_factor).min(T::MaximumFeeFactor::get());
+			if delivery_fee_factor > previous_factor {
+				Self::set_delivery_fee_factor(bridge_id, delivery_fee_factor);
+
+				log::info!(
+					target: LOG_TARGET,
+					"Bridge channel is congested. Increased fee factor of {:?} from {} to {}",
+					bridge_id,
+					previous_factor,
+					delivery_fee_factor,
+				);
+				Self::deposit_event(Event::DeliveryFeeFactorIncreased {
+					bridge_id: *bridge_id,
+					new_value: delivery_fee_factor,
+				});
+			}
+			// the factor may be above the maximum, if the maximum has been lowered
+			let delivery_fee_factor = delivery_fee_factor.max(previous_factor);
+
+			if delivery_fee_factor > T::FeeFactorAlertThreshold::get() {
+				let alerts = FeeFactorAlerts::<T, I>::mutate(|alerts| {
+					*alerts = alerts.saturating_add(1);
+					*alerts
+				});
+				log::warn!(
+					target: LOG_TARGET,
+					"Fee factor {} is above the alert threshold {}. Total alerts: {}",
+					delivery_fee_factor,
+					T::FeeFactorAlertThreshold::get(),
+					alerts,
+				);
+			}
+			Self::update_fee_factor_alert(Self::highest_delivery_fee_factor());
+		}
 	}
 }
 
@@ -243,78 +933,7 @@ impl<T: Config<I>, I: 'static> ExporterFor for Pallet<T, I> {
 		remote_location: &InteriorLocation,
 		message: &Xcm<()>,
 	) -> Option<(Location, Option<Asset>)> {
-		log::trace!(
-			target: LOG_TARGET,
-			"exporter_for - network: {network:?}, remote_location: {remote_location:?}, msg: {message:?}",
-		);
-		// ensure that the message is sent to the expected bridged network (if specified).
-		if let Some(bridged_network) = T::BridgedNetworkId::get() {
-			if *network != bridged_network {
-				log::trace!(
-					target: LOG_TARGET,
-					"Router with bridged_network_id {bridged_network:?} does not support bridging to network {network:?}!",
-				);
-				return None
-			}
-		}
-
-		// ensure that the message is sent to the expected bridged network and location.
-		let Some((bridge_hub_location, maybe_payment)) =
-			T::Bridges::exporter_for(network, remote_location, message)
-		else {
-			log::trace!(
-				target: LOG_TARGET,
-				"Router with bridged_network_id {:?} does not support bridging to network {:?} and remote_location {:?}!",
-				T::BridgedNetworkId::get(),
-				network,
-				remote_location,
-			);
-			return None
-		};
-
-		// take `base_fee` from `T::Brides`, but it has to be the same `T::FeeAsset`
-		let base_fee = match maybe_payment {
-			Some(payment) => match payment {
-				Asset { fun: Fungible(amount), id } if id.eq(&T::FeeAsset::get()) => amount,
-				invalid_asset => {
-					log::error!(
-						target: LOG_TARGET,
-						"Router with bridged_network_id {:?} is configured for `T::FeeAsset` {:?} which is not \
-						compatible with {:?} for bridge_hub_location: {:?} for bridging to {:?}/{:?}!",
-						T::BridgedNetworkId::get(),
-						T::FeeAsset::get(),
-						invalid_asset,
-						bridge_hub_location,
-						network,
-						remote_location,
-					);
-					return None
-				},
-			},
-			None => 0,
-		};
-
-		// compute fee amount. Keep in mind that this is only the bridge fee. The fee for sending
-		// message from this chain to child/sibling bridge hub is determined by the
-		// `Config::ToBridgeHubSender`
-		let message_size = message.encoded_size();
-		let message_fee = (message_size as u128).saturating_mul(T::ByteFee::get());
-		let fee_sum = base_fee.saturating_add(message_fee);
-		let fee_factor = Self::bridge().delivery_fee_factor;
-		let fee = fee_factor.saturating_mul_int(fee_sum);
-
-		let fee = if fee > 0 { Some((T::FeeAsset::get(), fee).into()) } else { None };
-
-		log::info!(
-			target: LOG_TARGET,
-			"Validate send message to {:?} ({} bytes) over bridge. Computed bridge fee {:?} using fee factor {}",
-			(network, remote_location),
-			message_size,
-			fee,
-			fee_factor
-		);
-
-		Some((bridge_hub_location, fee))
+		Self::bridge_fee(network, remote_location, message, message.encoded_size())
 	}
 }
 
@@ -322,7 +941,7 @@ impl<T: Config<I>, I: 'static> ExporterFor for Pallet<T, I> {
 // XCMP/DMP transport. This allows injecting dynamic message fees into XCM programs that
 // are going to the bridged network.
 impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
-	type Ticket = (u32, <T::ToBridgeHubSender as SendXcm>::Ticket);
+	type Ticket = (u32, BridgeId, Location, <T::ToBridgeHubSender as SendXcm>::Ticket);
 
 	fn validate(
 		dest: &mut Option<Location>,
@@ -330,6 +949,23 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 	) -> SendResult<Self::Ticket> {
 		log::trace!(target: LOG_TARGET, "validate - msg: {xcm:?}, destination: {dest:?}");
 
+		// destinations, which are known to be not routed by us, are rejected without asking the
+		// inner exporter again
+		if let Some(dest) = dest.as_ref() {
+			if Self::cached_routability(dest) == Some(DestinationRoutability::NotRoutable) {
+				return Err(SendError::NotApplicable)
+			}
+		}
+
+		// When the router is halted, we still want to return `NotApplicable` for destinations
+		// that are not routed by us, so that other routers may handle them. The inner exporter
+		// is called with clones, so the message is never consumed by the halted router.
+		if Self::is_halted() {
+			ViaBridgeHubExporter::<T, I>::validate(&mut dest.clone(), &mut xcm.clone())?;
+			log::trace!(target: LOG_TARGET, "validate - router is halted, destination: {dest:?}");
+			return Err(SendError::Transport("Router is halted"))
+		}
+
 		// In case of success, the `ViaBridgeHubExporter` can modify XCM instructions and consume
 		// `dest` / `xcm`, so we retain the clone of original message and the destination for later
 		// `DestinationVersion` validation.
@@ -357,7 +993,13 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 				// The bridge doesn't support oversized or overweight messages. Therefore, it's
 				// better to drop such messages here rather than at the bridge hub. Let's check the
 				// message size."
-				if message_size > HARD_MESSAGE_SIZE_LIMIT {
+				if message_size > T::HardMessageSizeLimit::get() {
+					// the event is best-effort - it is only kept if the caller commits changes
+					// of the failed validation
+					Self::deposit_event(Event::MessageDropped {
+						reason: DropReason::ExceedsMaxMessageSize,
+						message_size,
+					});
 					return Err(SendError::ExceedsMaxMessageSize)
 				}
 
@@ -368,16 +1010,35 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 				// local bridge hub may have a higher XCM version than the remote `dest`. Once
 				// again, it is better to discard such messages here than at the bridge hub (e.g.,
 				// to avoid losing funds).
-				let destination_version = T::DestinationVersion::get_version_for(&dest_clone)
-					.ok_or(SendError::DestinationUnsupported)?;
-				let _ = VersionedXcm::from(xcm_to_dest_clone)
-					.into_version(destination_version)
-					.map_err(|()| SendError::DestinationUnsupported)?;
+				let is_version_supported = Self::destination_version(&dest_clone)
+					.and_then(|version| {
+						VersionedXcm::from(xcm_to_dest_clone).into_version(version).ok()
+					})
+					.is_some();
+				if !is_version_supported {
+					Self::deposit_event(Event::MessageDropped {
+						reason: DropReason::UnsupportedVersion,
+						message_size,
+					});
+					return Err(SendError::DestinationUnsupported)
+				}
+
+				let (network, remote_location) =
+					ensure_is_remote(T::UniversalLocation::get(), dest_clone.clone())
+						.map_err(|_| SendError::NotApplicable)?;
+				let bridge_id = Self::bridge_id(&network, &remote_location);
 
-				Ok(((message_size, ticket), cost))
+				// Charge additional byte fees (if any) in their own assets.
+				let cost = Self::with_additional_byte_fees(cost, &bridge_id, message_size)
+					.ok_or(SendError::Fees)?;
+
+				Ok(((message_size, bridge_id, dest_clone, ticket), cost))
 			},
 			Err(e) => {
 				log::trace!(target: LOG_TARGET, "validate - ViaBridgeHubExporter - error: {e:?}");
+				if let (SendError::NotApplicable, Some(dest)) = (&e, dest_clone.as_ref()) {
+					Self::cache_routability(dest, DestinationRoutability::NotRoutable);
+				}
 				Err(e)
 			},
 		}
@@ -386,11 +1047,11 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
 		// use router to enqueue message to the sibling/child bridge hub. This also should handle
 		// payment for passing through this queue.
-		let (message_size, ticket) = ticket;
+		let (message_size, bridge_id, dest, ticket) = ticket;
 		let xcm_hash = ViaBridgeHubExporter::<T, I>::deliver(ticket)?;
 
 		// increase delivery fee factor if required
-		Self::on_message_sent_to_bridge(message_size);
+		Self::on_message_sent_to_bridge(&bridge_id, &dest, message_size);
 
 		log::trace!(target: LOG_TARGET, "deliver - message sent, xcm_hash: {xcm_hash:?}");
 		Ok(xcm_hash)
@@ -406,16 +1067,29 @@ impl<T: Config<I>, I: 'static> InspectMessageQueues for Pallet<T, I> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use mock::*;
 
-	use frame_support::traits::Hooks;
-	use sp_runtime::traits::One;
+	use bp_xcm_bridge_hub_router::XcmBridgeHubRouterApi;
+	use frame_support::{
+		dispatch_context::run_in_context,
+		traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	};
+	use sp_api::ProvideRuntimeApi;
+	use sp_runtime::{traits::One, DispatchError};
 
 	fn congested_bridge(delivery_fee_factor: FixedU128) -> BridgeState {
 		BridgeState { is_congested: true, delivery_fee_factor }
 	}
 
+	fn test_bridge_id() -> BridgeId {
+		(BridgedNetworkId::get(), Some(1000))
+	}
+
+	fn test_destination() -> Location {
+		Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)])
+	}
+
 	fn uncongested_bridge(delivery_fee_factor: FixedU128) -> BridgeState {
 		BridgeState { is_congested: false, delivery_fee_factor }
 	}
@@ -443,6 +1117,27 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn fee_factor_is_not_decreased_from_on_initialize_when_xcm_channel_is_highly_utilized() {
+		run_test(|| {
+			CongestionUtilizationThreshold::set(&Permill::from_percent(80));
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(FixedU128::from_rational(125, 100)));
+
+			// it should not decrease, because xcm channel is utilized above the threshold
+			TestWithBridgeHubChannel::set_utilization(Permill::from_percent(90));
+			let old_bridge = XcmBridgeHubRouter::bridge();
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert_eq!(XcmBridgeHubRouter::bridge(), old_bridge);
+
+			// but it decreases once the utilization drops to the threshold
+			TestWithBridgeHubChannel::set_utilization(Permill::from_percent(80));
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor < old_bridge.delivery_fee_factor
+			);
+		})
+	}
+
 	#[test]
 	fn fee_factor_is_not_decreased_from_on_initialize_when_bridge_has_reported_congestion() {
 		run_test(|| {
@@ -475,64 +1170,255 @@ mod tests {
 	}
 
 	#[test]
-	fn not_applicable_if_destination_is_within_other_network() {
+	fn fee_factors_are_isolated_per_bridge_if_configured() {
 		run_test(|| {
-			// unroutable dest
-			let dest = Location::new(2, [GlobalConsensus(ByGenesis([0; 32])), Parachain(1000)]);
-			let xcm: Xcm<()> = vec![ClearOrigin].into();
-
-			// check that router does not consume when `NotApplicable`
-			let mut xcm_wrapper = Some(xcm.clone());
+			PerBridgeFeeFactors::set(&true);
+			Bridge::<TestRuntime, ()>::put(congested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			let congested_dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			let other_dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1001)]);
+			let price = |dest: &Location| {
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut Some(Xcm(vec![])))
+					.unwrap()
+					.1
+			};
+			let other_price = price(&other_dest);
+
+			// the factor is only increased for the bridge the message is sent over
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(congested_dest.clone(), Xcm(vec![]))
+				.map(drop));
+			let congested_bridge_id = (BridgedNetworkId::get(), Some(1000));
+			let other_bridge_id = (BridgedNetworkId::get(), Some(1001));
+			assert!(
+				XcmBridgeHubRouter::delivery_fee_factor(&congested_bridge_id) >
+					MINIMAL_DELIVERY_FEE_FACTOR
+			);
 			assert_eq!(
-				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut xcm_wrapper),
-				Err(SendError::NotApplicable),
+				XcmBridgeHubRouter::delivery_fee_factor(&other_bridge_id),
+				MINIMAL_DELIVERY_FEE_FACTOR
 			);
-			// XCM is NOT consumed and untouched
-			assert_eq!(Some(xcm.clone()), xcm_wrapper);
-
-			// check the full `send_xcm`
-			assert_eq!(send_xcm::<XcmBridgeHubRouter>(dest, xcm,), Err(SendError::NotApplicable),);
-		});
-	}
-
-	#[test]
-	fn exceeds_max_message_size_if_size_is_above_hard_limit() {
-		run_test(|| {
-			// routable dest with XCM version
-			let dest =
-				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
-			// oversized XCM
-			let xcm: Xcm<()> = vec![ClearOrigin; HARD_MESSAGE_SIZE_LIMIT as usize].into();
-
-			// dest is routable with the inner router
-			assert_ok!(ViaBridgeHubExporter::<TestRuntime, ()>::validate(
-				&mut Some(dest.clone()),
-				&mut Some(xcm.clone())
-			));
-
-			// check for oversized message
-			let mut xcm_wrapper = Some(xcm.clone());
 			assert_eq!(
-				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut xcm_wrapper),
-				Err(SendError::ExceedsMaxMessageSize),
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				MINIMAL_DELIVERY_FEE_FACTOR
 			);
-			// XCM is consumed by the inner router
-			assert!(xcm_wrapper.is_none());
-
-			// check the full `send_xcm`
+			assert_eq!(price(&other_dest), other_price);
+			assert_ne!(price(&congested_dest), other_price);
+
+			// once the bridge is uncongested, the factor decreases back to the minimal value
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			while XcmBridgeHubRouter::delivery_fee_factor(&congested_bridge_id) >
+				MINIMAL_DELIVERY_FEE_FACTOR
+			{
+				XcmBridgeHubRouter::on_initialize(One::one());
+			}
 			assert_eq!(
-				send_xcm::<XcmBridgeHubRouter>(dest, xcm,),
-				Err(SendError::ExceedsMaxMessageSize),
+				XcmBridgeHubRouter::delivery_fee_factor(&congested_bridge_id),
+				MINIMAL_DELIVERY_FEE_FACTOR
 			);
-		});
+		})
 	}
 
 	#[test]
-	fn destination_unsupported_if_wrap_version_fails() {
+	fn stale_minimal_bridge_is_evicted_when_tracked_bridges_limit_is_reached() {
 		run_test(|| {
-			// routable dest but we don't know XCM version
-			let dest = UnknownXcmVersionForRoutableLocation::get();
-			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			PerBridgeFeeFactors::set(&true);
+			MaxTrackedBridges::set(&2);
+			Bridge::<TestRuntime, ()>::put(congested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			let send_to = |para_id, messages| {
+				let dest = Location::new(
+					2,
+					[GlobalConsensus(BridgedNetworkId::get()), Parachain(para_id)],
+				);
+				for _ in 0..messages {
+					assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), Xcm(vec![])).map(drop));
+				}
+			};
+			let bridge_id = |para_id| (BridgedNetworkId::get(), Some(para_id));
+			let is_tracked =
+				|para_id| DeliveryFeeFactors::<TestRuntime, ()>::contains_key(bridge_id(para_id));
+
+			// the bridge to 1000 becomes idle before the bridge to 1001
+			send_to(1000, 1);
+			send_to(1001, 3);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			let mut block_number = 1;
+			while XcmBridgeHubRouter::highest_delivery_fee_factor() > MINIMAL_DELIVERY_FEE_FACTOR {
+				block_number += 1;
+				System::set_block_number(block_number);
+				XcmBridgeHubRouter::on_initialize(block_number);
+			}
+			let idle_since = |para_id| {
+				DeliveryFeeFactors::<TestRuntime, ()>::get(bridge_id(para_id)).unwrap().updated_at
+			};
+			assert!(idle_since(1000) < idle_since(1001));
+			assert_eq!(DeliveryFeeFactors::<TestRuntime, ()>::count(), 2);
+
+			// the stale minimal factor is evicted to track the new bridge
+			System::set_block_number(block_number + 1);
+			Bridge::<TestRuntime, ()>::put(congested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			send_to(1002, 1);
+			assert_eq!(DeliveryFeeFactors::<TestRuntime, ()>::count(), 2);
+			assert!(!is_tracked(1000));
+			assert!(is_tracked(1001));
+			assert!(is_tracked(1002));
+
+			// already tracked bridges are updated without eviction
+			send_to(1001, 2);
+			assert_eq!(DeliveryFeeFactors::<TestRuntime, ()>::count(), 2);
+			assert!(is_tracked(1002));
+
+			// when there are no idle bridges, the least congested one is evicted
+			send_to(1003, 1);
+			assert_eq!(DeliveryFeeFactors::<TestRuntime, ()>::count(), 2);
+			assert!(is_tracked(1001));
+			assert!(!is_tracked(1002));
+			assert!(is_tracked(1003));
+			assert_eq!(
+				XcmBridgeHubRouter::delivery_fee_factor(&bridge_id(1002)),
+				MINIMAL_DELIVERY_FEE_FACTOR
+			);
+		})
+	}
+
+	#[test]
+	fn fee_factor_is_decreased_from_on_initialize_when_router_is_halted() {
+		run_test(|| {
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(FixedU128::from_rational(125, 100)));
+			PalletOperatingMode::<TestRuntime, ()>::put(BasicOperatingMode::Halted);
+
+			let old_bridge = XcmBridgeHubRouter::bridge();
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor < old_bridge.delivery_fee_factor
+			);
+		})
+	}
+
+	#[test]
+	fn set_operating_mode_requires_admin_origin() {
+		run_test(|| {
+			assert_noop!(
+				XcmBridgeHubRouter::set_operating_mode(
+					RuntimeOrigin::signed(1),
+					BasicOperatingMode::Halted
+				),
+				DispatchError::BadOrigin,
+			);
+			assert!(!XcmBridgeHubRouter::is_halted());
+
+			assert_ok!(XcmBridgeHubRouter::set_operating_mode(
+				RuntimeOrigin::root(),
+				BasicOperatingMode::Halted
+			));
+			assert!(XcmBridgeHubRouter::is_halted());
+		})
+	}
+
+	#[test]
+	fn halted_router_rejects_messages_and_resumes_when_unhalted() {
+		run_test(|| {
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+
+			assert_ok!(XcmBridgeHubRouter::set_operating_mode(
+				RuntimeOrigin::root(),
+				BasicOperatingMode::Halted
+			));
+
+			// message is rejected, but NOT consumed
+			let mut dest_wrapper = Some(dest.clone());
+			let mut xcm_wrapper = Some(xcm.clone());
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut dest_wrapper, &mut xcm_wrapper),
+				Err(SendError::Transport("Router is halted")),
+			);
+			assert_eq!(Some(dest.clone()), dest_wrapper);
+			assert_eq!(Some(xcm.clone()), xcm_wrapper);
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()),
+				Err(SendError::Transport("Router is halted")),
+			);
+			assert!(!TestToBridgeHubSender::is_message_sent());
+
+			// unroutable destinations are still reported as `NotApplicable`
+			let other_dest =
+				Location::new(2, [GlobalConsensus(ByGenesis([0; 32])), Parachain(1000)]);
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(other_dest, xcm.clone()),
+				Err(SendError::NotApplicable),
+			);
+
+			// after the router is resumed, messages are sent again
+			assert_ok!(XcmBridgeHubRouter::set_operating_mode(
+				RuntimeOrigin::root(),
+				BasicOperatingMode::Normal
+			));
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, xcm).map(drop));
+			assert!(TestToBridgeHubSender::is_message_sent());
+		})
+	}
+
+	#[test]
+	fn not_applicable_if_destination_is_within_other_network() {
+		run_test(|| {
+			// unroutable dest
+			let dest = Location::new(2, [GlobalConsensus(ByGenesis([0; 32])), Parachain(1000)]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+
+			// check that router does not consume when `NotApplicable`
+			let mut xcm_wrapper = Some(xcm.clone());
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut xcm_wrapper),
+				Err(SendError::NotApplicable),
+			);
+			// XCM is NOT consumed and untouched
+			assert_eq!(Some(xcm.clone()), xcm_wrapper);
+
+			// check the full `send_xcm`
+			assert_eq!(send_xcm::<XcmBridgeHubRouter>(dest, xcm,), Err(SendError::NotApplicable),);
+		});
+	}
+
+	#[test]
+	fn exceeds_max_message_size_if_size_is_above_hard_limit() {
+		run_test(|| {
+			// routable dest with XCM version
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			// oversized XCM
+			let xcm: Xcm<()> = vec![ClearOrigin; HARD_MESSAGE_SIZE_LIMIT as usize].into();
+
+			// dest is routable with the inner router
+			assert_ok!(ViaBridgeHubExporter::<TestRuntime, ()>::validate(
+				&mut Some(dest.clone()),
+				&mut Some(xcm.clone())
+			));
+
+			// check for oversized message
+			let mut xcm_wrapper = Some(xcm.clone());
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut xcm_wrapper),
+				Err(SendError::ExceedsMaxMessageSize),
+			);
+			// XCM is consumed by the inner router
+			assert!(xcm_wrapper.is_none());
+
+			// check the full `send_xcm`
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest, xcm,),
+				Err(SendError::ExceedsMaxMessageSize),
+			);
+		});
+	}
+
+	#[test]
+	fn destination_unsupported_if_wrap_version_fails() {
+		run_test(|| {
+			// routable dest but we don't know XCM version
+			let dest = UnknownXcmVersionForRoutableLocation::get();
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
 
 			// dest is routable with the inner router
 			assert_ok!(ViaBridgeHubExporter::<TestRuntime, ()>::validate(
@@ -590,6 +1476,139 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn byte_fee_is_not_scaled_by_factor_if_configured() {
+		run_test(|| {
+			let dest = Location::new(2, [GlobalConsensus(BridgedNetworkId::get())]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let msg_size = xcm.encoded_size();
+			let factor = FixedU128::from_rational(125, 100);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(factor));
+
+			// when byte fee is scaled: `(BASE_FEE + BYTE_FEE * msg_size) * F + HRMP_FEE`
+			let scaled_fee =
+				factor.saturating_mul_int(BASE_FEE + BYTE_FEE * (msg_size as u128)) + HRMP_FEE;
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut Some(xcm.clone()))
+					.unwrap()
+					.1
+					.get(0),
+				Some(&(BridgeFeeAsset::get(), scaled_fee).into()),
+			);
+
+			// when byte fee is flat: `BASE_FEE * F + BYTE_FEE * msg_size + HRMP_FEE`
+			ScaleByteFeeByFactor::set(&false);
+			let flat_fee =
+				factor.saturating_mul_int(BASE_FEE) + BYTE_FEE * (msg_size as u128) + HRMP_FEE;
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest), &mut Some(xcm)).unwrap().1.get(0),
+				Some(&(BridgeFeeAsset::get(), flat_fee).into()),
+			);
+			assert!(flat_fee < scaled_fee);
+		});
+	}
+
+	#[test]
+	fn additional_byte_fees_are_added_to_the_delivery_price() {
+		run_test(|| {
+			let dest = Location::new(2, [GlobalConsensus(BridgedNetworkId::get())]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let msg_size = xcm.encoded_size() as u128;
+			let first_asset = AssetId(Location::new(1, [Parachain(1000), GeneralIndex(1)]));
+			let second_asset = AssetId(Location::new(1, [Parachain(1000), GeneralIndex(2)]));
+			AdditionalByteFees::set(&vec![(first_asset.clone(), 10), (second_asset.clone(), 20)]);
+
+			let cost =
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut Some(xcm.clone()))
+					.unwrap()
+					.1;
+			let expected_fee = BASE_FEE + BYTE_FEE * msg_size + HRMP_FEE;
+			assert_eq!(cost.len(), 3);
+			assert!(cost.inner().contains(&(BridgeFeeAsset::get(), expected_fee).into()));
+			assert!(cost.inner().contains(&(first_asset.clone(), 10 * msg_size).into()));
+			assert!(cost.inner().contains(&(second_asset.clone(), 20 * msg_size).into()));
+
+			// additional byte fees are scaled by the fee factor too
+			let factor = FixedU128::from_rational(125, 100);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(factor));
+			let cost = XcmBridgeHubRouter::validate(&mut Some(dest), &mut Some(xcm)).unwrap().1;
+			assert!(cost
+				.inner()
+				.contains(&(first_asset, factor.saturating_mul_int(10 * msg_size)).into()));
+			assert!(cost
+				.inner()
+				.contains(&(second_asset, factor.saturating_mul_int(20 * msg_size)).into()));
+		});
+	}
+
+	#[test]
+	fn delivery_price_with_too_many_fee_assets_is_rejected() {
+		run_test(|| {
+			let dest = Location::new(2, [GlobalConsensus(BridgedNetworkId::get())]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let first_asset = AssetId(Location::new(1, [Parachain(1000), GeneralIndex(1)]));
+			let second_asset = AssetId(Location::new(1, [Parachain(1000), GeneralIndex(2)]));
+			MaxFeeAssets::set(&2);
+
+			// fees in the same asset are merged, so the cost fits the limit
+			AdditionalByteFees::set(&vec![(BridgeFeeAsset::get(), 10), (first_asset.clone(), 10)]);
+			let cost =
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut Some(xcm.clone()))
+					.unwrap()
+					.1;
+			assert_eq!(cost.len(), 2);
+			let message_size = xcm.encoded_size() as u32;
+			assert_eq!(
+				XcmBridgeHubRouter::calculate_fee(dest.clone(), message_size).map(|f| f.len()),
+				Some(2),
+			);
+
+			// otherwise the message is rejected and the fee is unknown
+			AdditionalByteFees::set(&vec![(first_asset, 10), (second_asset, 10)]);
+			assert_eq!(XcmBridgeHubRouter::calculate_fee(dest.clone(), message_size), None);
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest), &mut Some(xcm)).map(drop),
+				Err(SendError::Fees),
+			);
+		});
+	}
+
+	#[test]
+	fn runtime_api_returns_delivery_fee_factor_and_fee() {
+		run_test(|| {
+			let delivery_fee_factor = FixedU128::from_u32(2);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(delivery_fee_factor));
+			let runtime_api = TestClient.runtime_api();
+			let dest = Location::new(2, [GlobalConsensus(BridgedNetworkId::get())]);
+			let message_size = 100;
+
+			assert_eq!(
+				runtime_api.delivery_fee_factor(Default::default()).unwrap(),
+				delivery_fee_factor,
+			);
+
+			let state_root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+			let expected_fee = delivery_fee_factor
+				.saturating_mul_int(BASE_FEE + BYTE_FEE * message_size as u128);
+			assert_eq!(
+				runtime_api
+					.calculate_fee(Default::default(), dest.into(), message_size)
+					.unwrap(),
+				Some(Assets::from((BridgeFeeAsset::get(), expected_fee)).into()),
+			);
+			assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), state_root);
+
+			// the fee is not known for destinations that are not routed by us
+			let unknown_dest = Location::new(2, [GlobalConsensus(ByGenesis([0; 32]))]);
+			assert_eq!(
+				runtime_api
+					.calculate_fee(Default::default(), unknown_dest.into(), message_size)
+					.unwrap(),
+				None,
+			);
+		});
+	}
+
 	#[test]
 	fn sent_message_doesnt_increase_factor_if_xcm_channel_is_uncongested() {
 		run_test(|| {
@@ -624,6 +1643,303 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn sent_message_increases_factor_proportionally_to_xcm_channel_utilization() {
+		let factor_after_message_sent = |utilization| {
+			run_test(|| {
+				CongestionUtilizationThreshold::set(&Permill::from_percent(80));
+				TestWithBridgeHubChannel::set_utilization(utilization);
+
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(
+					Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]),
+					vec![ClearOrigin].into(),
+				)
+				.map(drop));
+
+				XcmBridgeHubRouter::bridge().delivery_fee_factor
+			})
+		};
+
+		// utilization below or at the threshold doesn't increase the factor
+		assert_eq!(
+			factor_after_message_sent(Permill::from_percent(50)),
+			MINIMAL_DELIVERY_FEE_FACTOR
+		);
+		assert_eq!(
+			factor_after_message_sent(Permill::from_percent(80)),
+			MINIMAL_DELIVERY_FEE_FACTOR
+		);
+
+		// the more the channel is utilized above the threshold, the steeper the factor grows
+		let factor_at_90 = factor_after_message_sent(Permill::from_percent(90));
+		let factor_at_100 = factor_after_message_sent(Permill::from_percent(100));
+		assert!(MINIMAL_DELIVERY_FEE_FACTOR < factor_at_90);
+		assert!(factor_at_90 < factor_at_100);
+
+		// the fully utilized channel increases the factor as much as the congested one
+		let factor_when_congested = run_test(|| {
+			TestWithBridgeHubChannel::make_congested();
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]),
+				vec![ClearOrigin].into(),
+			)
+			.map(drop));
+			XcmBridgeHubRouter::bridge().delivery_fee_factor
+		});
+		assert_eq!(factor_at_100, factor_when_congested);
+	}
+
+	#[test]
+	fn sent_message_factor_increase_is_clamped() {
+		run_test(|| {
+			TestWithBridgeHubChannel::make_congested();
+			let max_increase = FixedU128::from_rational(106, 100);
+			MaxFeeFactorIncreasePerSend::set(&max_increase);
+
+			// the near-limit message would increase the factor by ~1.081 without the clamp
+			XcmBridgeHubRouter::on_message_sent_to_bridge(
+				&test_bridge_id(),
+				&test_destination(),
+				HARD_MESSAGE_SIZE_LIMIT,
+			);
+			assert_eq!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				MINIMAL_DELIVERY_FEE_FACTOR * max_increase
+			);
+
+			// small messages are not affected by the clamp
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			XcmBridgeHubRouter::on_message_sent_to_bridge(
+				&test_bridge_id(),
+				&test_destination(),
+				0,
+			);
+			assert_eq!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				MINIMAL_DELIVERY_FEE_FACTOR * EXPONENTIAL_FEE_BASE
+			);
+		});
+	}
+
+	#[test]
+	fn sent_message_increases_factor_by_reported_utilization() {
+		run_test(|| {
+			let factor_after_message = || {
+				Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+				XcmBridgeHubRouter::on_message_sent_to_bridge(
+					&test_bridge_id(),
+					&test_destination(),
+					0,
+				);
+				XcmBridgeHubRouter::bridge().delivery_fee_factor
+			};
+
+			// when the utilization is not reported, the congested channel increases the factor in
+			// full
+			TestWithBridgeHubChannel::make_congested();
+			assert_eq!(factor_after_message(), MINIMAL_DELIVERY_FEE_FACTOR * EXPONENTIAL_FEE_BASE);
+
+			// otherwise the increase is scaled by the reported utilization
+			TestWithBridgeHubChannel::set_utilization(Permill::from_percent(50));
+			assert_eq!(
+				factor_after_message(),
+				MINIMAL_DELIVERY_FEE_FACTOR *
+					(FixedU128::from_u32(1) +
+						(EXPONENTIAL_FEE_BASE - FixedU128::from_u32(1)) *
+							FixedU128::from_rational(1, 2)),
+			);
+			TestWithBridgeHubChannel::set_utilization(Permill::zero());
+			assert_eq!(factor_after_message(), MINIMAL_DELIVERY_FEE_FACTOR);
+		});
+	}
+
+	#[test]
+	fn sent_message_never_increases_factor_above_maximum() {
+		run_test(|| {
+			System::set_block_number(1);
+			TestWithBridgeHubChannel::make_congested();
+			let maximum_factor = FixedU128::from_u32(2);
+			MaximumFeeFactor::set(&maximum_factor);
+
+			let mut increases = 0;
+			for _ in 0..100 {
+				let previous_factor = XcmBridgeHubRouter::bridge().delivery_fee_factor;
+				XcmBridgeHubRouter::on_message_sent_to_bridge(
+					&test_bridge_id(),
+					&test_destination(),
+					0,
+				);
+				let delivery_fee_factor = XcmBridgeHubRouter::bridge().delivery_fee_factor;
+				assert!(delivery_fee_factor <= maximum_factor);
+				if delivery_fee_factor != previous_factor {
+					increases += 1;
+				}
+			}
+			assert_eq!(XcmBridgeHubRouter::bridge().delivery_fee_factor, maximum_factor);
+
+			// the event is only emitted when the factor has actually changed
+			let increase_events = System::events()
+				.iter()
+				.filter(|record| {
+					matches!(
+						record.event,
+						RuntimeEvent::XcmBridgeHubRouter(Event::DeliveryFeeFactorIncreased { .. })
+					)
+				})
+				.count();
+			assert!(increases < 100);
+			assert_eq!(increase_events, increases);
+		});
+	}
+
+	#[test]
+	fn sent_message_factor_increase_follows_message_size_fee_curve() {
+		run_test(|| {
+			TestWithBridgeHubChannel::make_congested();
+			let factor_after_message = |curve: MessageSizeFeeCurve, message_size: u32| {
+				MessageSizeFee::set(&curve);
+				Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+				XcmBridgeHubRouter::on_message_sent_to_bridge(
+					&test_bridge_id(),
+					&test_destination(),
+					message_size,
+				);
+				XcmBridgeHubRouter::bridge().delivery_fee_factor
+			};
+			let (small, large) = (1024, 16 * 1024);
+
+			// small messages cost the same under both curves
+			assert_eq!(
+				factor_after_message(MessageSizeFeeCurve::Linear, small),
+				MINIMAL_DELIVERY_FEE_FACTOR *
+					(EXPONENTIAL_FEE_BASE + FixedU128::from_rational(1, 1000))
+			);
+			assert_eq!(
+				factor_after_message(MessageSizeFeeCurve::Quadratic, small),
+				factor_after_message(MessageSizeFeeCurve::Linear, small),
+			);
+
+			// large messages cost more under the quadratic curve
+			assert_eq!(
+				factor_after_message(MessageSizeFeeCurve::Linear, large),
+				MINIMAL_DELIVERY_FEE_FACTOR *
+					(EXPONENTIAL_FEE_BASE + FixedU128::from_rational(16, 1000))
+			);
+			assert_eq!(
+				factor_after_message(MessageSizeFeeCurve::Quadratic, large),
+				MINIMAL_DELIVERY_FEE_FACTOR *
+					(EXPONENTIAL_FEE_BASE + FixedU128::from_rational(256, 1000))
+			);
+
+			// the piecewise curve uses the entry for the largest size not exceeding the message
+			let table = MessageSizeFeeCurve::Piecewise(vec![
+				(4, FixedU128::from_rational(1, 100)),
+				(8, FixedU128::from_rational(5, 100)),
+			]);
+			assert_eq!(
+				factor_after_message(table.clone(), small),
+				MINIMAL_DELIVERY_FEE_FACTOR * EXPONENTIAL_FEE_BASE
+			);
+			assert_eq!(
+				factor_after_message(table, large),
+				MINIMAL_DELIVERY_FEE_FACTOR *
+					(EXPONENTIAL_FEE_BASE + FixedU128::from_rational(5, 100))
+			);
+		});
+	}
+
+	#[test]
+	fn steeper_fee_bases_increase_factor_faster() {
+		run_test(|| {
+			TestWithBridgeHubChannel::make_congested();
+			let factor_after_messages = |exponential_fee_base, message_size_fee_base| {
+				ExponentialFeeBase::set(&exponential_fee_base);
+				MessageSizeFeeBase::set(&message_size_fee_base);
+				Bridge::<TestRuntime, ()>::put(uncongested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+				for _ in 0..8 {
+					XcmBridgeHubRouter::on_message_sent_to_bridge(
+						&test_bridge_id(),
+						&test_destination(),
+						4 * 1024,
+					);
+				}
+				XcmBridgeHubRouter::bridge().delivery_fee_factor
+			};
+			let default_factor = factor_after_messages(EXPONENTIAL_FEE_BASE, MESSAGE_SIZE_FEE_BASE);
+
+			// steeper exponential base
+			let steeper_exponential_fee_base = FixedU128::from_rational(110, 100);
+			assert!(
+				factor_after_messages(steeper_exponential_fee_base, MESSAGE_SIZE_FEE_BASE) >
+					default_factor
+			);
+
+			// steeper message size base
+			let steeper_message_size_fee_base = FixedU128::from_rational(1, 100);
+			assert!(
+				factor_after_messages(EXPONENTIAL_FEE_BASE, steeper_message_size_fee_base) >
+					default_factor
+			);
+
+			// and the steeper exponential base decreases the factor faster
+			ExponentialFeeBase::set(&steeper_exponential_fee_base);
+			TestWithBridgeHubChannel::make_uncongested();
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert_eq!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				default_factor / steeper_exponential_fee_base,
+			);
+		});
+	}
+
+	#[test]
+	fn fee_factor_is_reset_on_runtime_upgrade_if_configured() {
+		run_test(|| {
+			System::set_block_number(1);
+			FeeFactorOnUpgrade::set(&Some(MINIMAL_DELIVERY_FEE_FACTOR));
+			let stale_factor = FixedU128::from_u32(10);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(stale_factor));
+			StorageVersion::new(0).put::<XcmBridgeHubRouter>();
+
+			migration::MigrateV0ToV1::<TestRuntime, (), FeeFactorOnUpgrade>::on_runtime_upgrade();
+
+			assert_eq!(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				MINIMAL_DELIVERY_FEE_FACTOR
+			);
+			assert_eq!(
+				XcmBridgeHubRouter::on_chain_storage_version(),
+				XcmBridgeHubRouter::in_code_storage_version()
+			);
+			System::assert_has_event(RuntimeEvent::XcmBridgeHubRouter(
+				Event::DeliveryFeeFactorDecreased { new_value: MINIMAL_DELIVERY_FEE_FACTOR },
+			));
+
+			// the next runtime upgrade doesn't reset the factor until the storage version is
+			// bumped again
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(stale_factor));
+			migration::MigrateV0ToV1::<TestRuntime, (), FeeFactorOnUpgrade>::on_runtime_upgrade();
+			assert_eq!(XcmBridgeHubRouter::bridge().delivery_fee_factor, stale_factor);
+		});
+	}
+
+	#[test]
+	fn fee_factor_is_preserved_on_runtime_upgrade_if_not_configured() {
+		run_test(|| {
+			let stale_factor = FixedU128::from_u32(10);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(stale_factor));
+			StorageVersion::new(0).put::<XcmBridgeHubRouter>();
+
+			migration::MigrateV0ToV1::<TestRuntime, (), ()>::on_runtime_upgrade();
+
+			assert_eq!(XcmBridgeHubRouter::bridge().delivery_fee_factor, stale_factor);
+			assert_eq!(
+				XcmBridgeHubRouter::on_chain_storage_version(),
+				XcmBridgeHubRouter::in_code_storage_version()
+			);
+		});
+	}
+
 	#[test]
 	fn sent_message_increases_factor_if_bridge_has_reported_congestion() {
 		run_test(|| {
@@ -643,6 +1959,110 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn sustained_congestion_increments_fee_factor_alerts() {
+		run_test(|| {
+			TestWithBridgeHubChannel::make_congested();
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+
+			// there are no alerts while the factor is below the threshold
+			while XcmBridgeHubRouter::bridge().delivery_fee_factor <=
+				FeeFactorAlertThreshold::get()
+			{
+				assert_eq!(XcmBridgeHubRouter::fee_factor_alerts(), 0);
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), vec![ClearOrigin].into())
+					.map(drop));
+			}
+			assert_eq!(XcmBridgeHubRouter::fee_factor_alerts(), 1);
+
+			// every message that is sent above the threshold is counted
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, vec![ClearOrigin].into()).map(drop));
+			assert_eq!(XcmBridgeHubRouter::fee_factor_alerts(), 2);
+		});
+	}
+
+	#[test]
+	fn fee_factor_alert_is_emitted_once_per_threshold_crossing() {
+		run_test(|| {
+			System::set_block_number(1);
+			Bridge::<TestRuntime, ()>::put(congested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			let count_events = |cleared: bool| {
+				System::events()
+					.iter()
+					.filter(|record| match record.event {
+						RuntimeEvent::XcmBridgeHubRouter(Event::DeliveryFeeFactorAlert {
+							..
+						}) => !cleared,
+						RuntimeEvent::XcmBridgeHubRouter(Event::DeliveryFeeFactorAlertCleared {
+							..
+						}) => cleared,
+						_ => false,
+					})
+					.count()
+			};
+
+			// the alert is emitted once the factor crosses the threshold and is not repeated
+			while XcmBridgeHubRouter::bridge().delivery_fee_factor <=
+				FeeFactorAlertThreshold::get()
+			{
+				assert_eq!(count_events(false), 0);
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), vec![ClearOrigin].into())
+					.map(drop));
+			}
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, vec![ClearOrigin].into()).map(drop));
+			assert_eq!(count_events(false), 1);
+			assert_eq!(count_events(true), 0);
+
+			// the clear event is emitted once the factor falls back and is not repeated
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(
+				XcmBridgeHubRouter::bridge().delivery_fee_factor,
+			));
+			while XcmBridgeHubRouter::bridge().delivery_fee_factor >
+				FeeFactorAlertThreshold::get()
+			{
+				assert_eq!(count_events(true), 0);
+				XcmBridgeHubRouter::on_initialize(One::one());
+			}
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert_eq!(count_events(true), 1);
+			assert_eq!(count_events(false), 1);
+		});
+	}
+
+	#[test]
+	fn inspect_routed_destination_reports_rewritten_destination() {
+		run_test(|| {
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+
+			// by default, the sender consumes the destination
+			assert_eq!(
+				XcmBridgeHubRouter::inspect_routed_destination(
+					dest.clone(),
+					vec![ClearOrigin].into(),
+				),
+				Ok(RoutedDestination { original: SiblingBridgeHubLocation::get(), rewritten: None }),
+			);
+
+			// but it may also rewrite it
+			let rewritten = Location::new(1, [Parachain(1003)]);
+			REWRITTEN_DESTINATION.with(|r| *r.borrow_mut() = Some(rewritten.clone()));
+			assert_eq!(
+				XcmBridgeHubRouter::inspect_routed_destination(dest, vec![ClearOrigin].into()),
+				Ok(RoutedDestination {
+					original: SiblingBridgeHubLocation::get(),
+					rewritten: Some(rewritten),
+				}),
+			);
+
+			// nothing is sent
+			assert!(!TestToBridgeHubSender::is_message_sent());
+		});
+	}
+
 	#[test]
 	fn get_messages_works() {
 		run_test(|| {
@@ -674,4 +2094,149 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn send_message_weight_depends_on_message_size() {
+		let small = XcmBridgeHubRouter::send_message_weight(1);
+		let near_limit = XcmBridgeHubRouter::send_message_weight(HARD_MESSAGE_SIZE_LIMIT);
+		assert!(small.ref_time() < near_limit.ref_time());
+		assert!(small.all_lte(near_limit));
+	}
+
+	#[test]
+	fn destination_version_is_resolved_once_per_dispatch() {
+		run_test(|| {
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+
+			// two messages to the same destination in the same dispatch
+			let resolutions_before = version_resolutions();
+			run_in_context(|| {
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()).map(drop));
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()).map(drop));
+				assert_eq!(
+					XcmBridgeHubRouter::cached_routability(&dest),
+					Some(DestinationRoutability::Routable(XCM_VERSION)),
+				);
+			});
+			assert_eq!(version_resolutions(), resolutions_before + 1);
+
+			// the cache is dropped with the dispatch context, so the version is resolved again
+			run_in_context(|| {
+				assert_eq!(XcmBridgeHubRouter::cached_routability(&dest), None);
+				assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()).map(drop));
+			});
+			assert_eq!(version_resolutions(), resolutions_before + 2);
+
+			// nothing is cached outside of the dispatch context
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()).map(drop));
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, xcm).map(drop));
+			assert_eq!(version_resolutions(), resolutions_before + 4);
+		})
+	}
+
+	#[test]
+	fn not_routable_destination_is_cached_until_the_end_of_dispatch() {
+		run_test(|| {
+			let dest = Location::new(1, [Parachain(1000)]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+
+			run_in_context(|| {
+				assert_eq!(
+					send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()),
+					Err(SendError::NotApplicable),
+				);
+				assert_eq!(
+					XcmBridgeHubRouter::cached_routability(&dest),
+					Some(DestinationRoutability::NotRoutable),
+				);
+				assert_eq!(
+					send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm),
+					Err(SendError::NotApplicable),
+				);
+			});
+			run_in_context(|| assert_eq!(XcmBridgeHubRouter::cached_routability(&dest), None));
+		})
+	}
+
+	#[test]
+	fn messages_above_configured_size_limit_are_rejected() {
+		run_test(|| {
+			let dest = test_destination();
+			let xcm: Xcm<()> = vec![ClearOrigin; 2 * 1024].into();
+			assert!(xcm.encoded_size() < HARD_MESSAGE_SIZE_LIMIT as usize);
+
+			// the message fits the default limit
+			assert_ok!(XcmBridgeHubRouter::validate(
+				&mut Some(dest.clone()),
+				&mut Some(xcm.clone())
+			));
+
+			// but not the smaller one
+			HardMessageSizeLimit::set(&1024);
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest, xcm),
+				Err(SendError::ExceedsMaxMessageSize),
+			);
+		})
+	}
+
+	#[test]
+	fn message_dropped_event_is_emitted_for_oversized_message() {
+		run_test(|| {
+			System::set_block_number(1);
+			let dest = test_destination();
+			let xcm: Xcm<()> = vec![ClearOrigin; HARD_MESSAGE_SIZE_LIMIT as usize].into();
+			let message_size = xcm.encoded_size() as u32;
+
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest, xcm),
+				Err(SendError::ExceedsMaxMessageSize),
+			);
+			System::assert_last_event(RuntimeEvent::XcmBridgeHubRouter(Event::MessageDropped {
+				reason: DropReason::ExceedsMaxMessageSize,
+				message_size,
+			}));
+		})
+	}
+
+	#[test]
+	fn message_dropped_event_is_emitted_for_unsupported_destination_version() {
+		run_test(|| {
+			System::set_block_number(1);
+			let dest = UnknownXcmVersionForRoutableLocation::get();
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let message_size = xcm.encoded_size() as u32;
+
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest, xcm),
+				Err(SendError::DestinationUnsupported),
+			);
+			System::assert_last_event(RuntimeEvent::XcmBridgeHubRouter(Event::MessageDropped {
+				reason: DropReason::UnsupportedVersion,
+				message_size,
+			}));
+		})
+	}
+
+	#[test]
+	fn unsupported_destination_version_is_not_cached() {
+		run_test(|| {
+			let dest = UnknownXcmVersionForRoutableLocation::get();
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+
+			run_in_context(|| {
+				assert_eq!(
+					send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm.clone()),
+					Err(SendError::DestinationUnsupported),
+				);
+				assert_eq!(
+					send_xcm::<XcmBridgeHubRouter>(dest.clone(), xcm),
+					Err(SendError::DestinationUnsupported),
+				);
+				assert_eq!(XcmBridgeHubRouter::cached_routability(&dest), None);
+			});
+		})
+	}
 }
@@ -0,0 +1,69 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM bridge hub router pallet storage migrations.
+
+use crate::{Config, Event, Pallet, LOG_TARGET};
+
+use frame_support::{
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::FixedU128;
+use sp_std::marker::PhantomData;
+
+/// Resets the delivery fee factors of all bridges to the `ResetFactor`, unless they are already
+/// lower. If the `ResetFactor` is `None`, the factors are kept.
+pub struct ResetDeliveryFeeFactors<T, I, ResetFactor>(PhantomData<(T, I, ResetFactor)>);
+
+impl<T, I, ResetFactor> UncheckedOnRuntimeUpgrade for ResetDeliveryFeeFactors<T, I, ResetFactor>
+where
+	T: Config<I>,
+	I: 'static,
+	ResetFactor: Get<Option<FixedU128>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let Some(reset_factor) = ResetFactor::get() else { return Weight::zero() };
+		let reset =
+			Pallet::<T, I>::update_delivery_fee_factors(|factor| factor.min(reset_factor));
+		if reset == 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		log::info!(
+			target: LOG_TARGET,
+			"Reset {} fee factor(s) to {} on runtime upgrade",
+			reset,
+			reset_factor,
+		);
+
+		Pallet::<T, I>::update_fee_factor_alert(Pallet::<T, I>::highest_delivery_fee_factor());
+		Pallet::<T, I>::deposit_event(Event::DeliveryFeeFactorDecreased {
+			new_value: reset_factor,
+		});
+		T::DbWeight::get().reads_writes(2 + reset as u64, 1 + reset as u64)
+	}
+}
+
+/// Migrate the pallet storage from `0` to `1`, resetting the delivery fee factors to the
+/// `ResetFactor` (if it is set). Use `()` to keep the factors.
+pub type MigrateV0ToV1<T, I, ResetFactor> = frame_support::migrations::VersionedMigration<
+	0,
+	1,
+	ResetDeliveryFeeFactors<T, I, ResetFactor>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
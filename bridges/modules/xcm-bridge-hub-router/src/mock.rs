@@ -17,15 +17,18 @@
 #![cfg(test)]
 
 use crate as pallet_xcm_bridge_hub_router;
+use crate::{
+	MessageSizeFeeCurve, EXPONENTIAL_FEE_BASE, HARD_MESSAGE_SIZE_LIMIT, MESSAGE_SIZE_FEE_BASE,
+};
 
-use bp_xcm_bridge_hub_router::XcmChannelStatusProvider;
+use bp_xcm_bridge_hub_router::{XcmBridgeHubRouterApi, XcmChannelStatusProvider};
 use codec::Encode;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{Contains, Equals},
 };
 use frame_system::EnsureRoot;
-use sp_runtime::{traits::ConstU128, BuildStorage};
+use sp_runtime::{traits::ConstU128, BuildStorage, FixedU128, Permill};
 use sp_std::cell::RefCell;
 use xcm::prelude::*;
 use xcm_builder::{InspectMessageQueues, NetworkExportTable, NetworkExportTableItem};
@@ -44,7 +47,7 @@ construct_runtime! {
 	pub enum TestRuntime
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		XcmBridgeHubRouter: pallet_xcm_bridge_hub_router::{Pallet, Storage},
+		XcmBridgeHubRouter: pallet_xcm_bridge_hub_router::{Pallet, Call, Storage, Event<T>},
 	}
 }
 
@@ -64,6 +67,20 @@ parameter_types! {
 			)
 		];
 	pub UnknownXcmVersionForRoutableLocation: Location = Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(9999)]);
+	pub storage ScaleByteFeeByFactor: bool = true;
+	pub storage AdditionalByteFees: Vec<(AssetId, u128)> = vec![];
+	pub storage MaxFeeAssets: u32 = 4;
+	pub storage HardMessageSizeLimit: u32 = HARD_MESSAGE_SIZE_LIMIT;
+	pub const FeeFactorAlertThreshold: FixedU128 = FixedU128::from_u32(2);
+	pub storage CongestionUtilizationThreshold: Permill = Permill::zero();
+	pub storage MaxFeeFactorIncreasePerSend: FixedU128 = FixedU128::from_inner(u128::MAX);
+	pub storage MaximumFeeFactor: FixedU128 = FixedU128::from_inner(u128::MAX);
+	pub storage ExponentialFeeBase: FixedU128 = EXPONENTIAL_FEE_BASE;
+	pub storage MessageSizeFeeBase: FixedU128 = MESSAGE_SIZE_FEE_BASE;
+	pub storage MessageSizeFee: MessageSizeFeeCurve = MessageSizeFeeCurve::Linear;
+	pub storage FeeFactorOnUpgrade: Option<FixedU128> = None;
+	pub storage PerBridgeFeeFactors: bool = false;
+	pub storage MaxTrackedBridges: u32 = 16;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -72,6 +89,7 @@ impl frame_system::Config for TestRuntime {
 }
 
 impl pallet_xcm_bridge_hub_router::Config<()> for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 
 	type UniversalLocation = UniversalLocation;
@@ -81,11 +99,32 @@ impl pallet_xcm_bridge_hub_router::Config<()> for TestRuntime {
 		LatestOrNoneForLocationVersionChecker<Equals<UnknownXcmVersionForRoutableLocation>>;
 
 	type BridgeHubOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type ToBridgeHubSender = TestToBridgeHubSender;
 	type WithBridgeHubChannel = TestWithBridgeHubChannel;
 
 	type ByteFee = ConstU128<BYTE_FEE>;
+	type ScaleByteFeeByFactor = ScaleByteFeeByFactor;
 	type FeeAsset = BridgeFeeAsset;
+	type AdditionalByteFees = AdditionalByteFees;
+	type MaxFeeAssets = MaxFeeAssets;
+	type HardMessageSizeLimit = HardMessageSizeLimit;
+	type FeeFactorAlertThreshold = FeeFactorAlertThreshold;
+	type CongestionUtilizationThreshold = CongestionUtilizationThreshold;
+	type MaxFeeFactorIncreasePerSend = MaxFeeFactorIncreasePerSend;
+	type MaximumFeeFactor = MaximumFeeFactor;
+	type ExponentialFeeBase = ExponentialFeeBase;
+	type MessageSizeFeeBase = MessageSizeFeeBase;
+	type MessageSizeFeeCurve = MessageSizeFee;
+	type PerBridgeFeeFactors = PerBridgeFeeFactors;
+	type MaxTrackedBridges = MaxTrackedBridges;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config<()> for TestRuntime {
+	fn make_congested() {
+		TestWithBridgeHubChannel::make_congested();
+	}
 }
 
 pub struct LatestOrNoneForLocationVersionChecker<Location>(sp_std::marker::PhantomData<Location>);
@@ -93,6 +132,7 @@ impl<LocationValue: Contains<Location>> GetVersion
 	for LatestOrNoneForLocationVersionChecker<LocationValue>
 {
 	fn get_version_for(dest: &Location) -> Option<XcmVersion> {
+		VERSION_RESOLUTIONS.with(|r| *r.borrow_mut() += 1);
 		if LocationValue::contains(dest) {
 			return None
 		}
@@ -100,6 +140,15 @@ impl<LocationValue: Contains<Location>> GetVersion
 	}
 }
 
+thread_local! {
+	pub static VERSION_RESOLUTIONS: RefCell<u32> = RefCell::new(0);
+}
+
+/// Returns number of `DestinationVersion::get_version_for` calls.
+pub fn version_resolutions() -> u32 {
+	VERSION_RESOLUTIONS.with(|r| *r.borrow())
+}
+
 pub struct TestToBridgeHubSender;
 
 impl TestToBridgeHubSender {
@@ -110,6 +159,8 @@ impl TestToBridgeHubSender {
 
 thread_local! {
 	pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = RefCell::new(Vec::new());
+	/// If set, `TestToBridgeHubSender` leaves this location in place of the consumed destination.
+	pub static REWRITTEN_DESTINATION: RefCell<Option<Location>> = RefCell::new(None);
 }
 
 impl SendXcm for TestToBridgeHubSender {
@@ -120,6 +171,7 @@ impl SendXcm for TestToBridgeHubSender {
 		message: &mut Option<Xcm<()>>,
 	) -> SendResult<Self::Ticket> {
 		let pair = (destination.take().unwrap(), message.take().unwrap());
+		*destination = REWRITTEN_DESTINATION.with(|r| r.borrow().clone());
 		Ok((pair, (BridgeFeeAsset::get(), HRMP_FEE).into()))
 	}
 
@@ -153,12 +205,58 @@ impl TestWithBridgeHubChannel {
 	pub fn make_congested() {
 		frame_support::storage::unhashed::put(b"TestWithBridgeHubChannel.Congested", &true);
 	}
+
+	pub fn make_uncongested() {
+		frame_support::storage::unhashed::put(b"TestWithBridgeHubChannel.Congested", &false);
+	}
+
+	pub fn set_utilization(utilization: Permill) {
+		frame_support::storage::unhashed::put(
+			b"TestWithBridgeHubChannel.Utilization",
+			&utilization,
+		);
+	}
 }
 
 impl XcmChannelStatusProvider for TestWithBridgeHubChannel {
 	fn is_congested() -> bool {
 		frame_support::storage::unhashed::get_or_default(b"TestWithBridgeHubChannel.Congested")
 	}
+
+	fn utilization(_destination: Option<&Location>) -> Permill {
+		frame_support::storage::unhashed::get(b"TestWithBridgeHubChannel.Utilization")
+			.unwrap_or_else(|| if Self::is_congested() { Permill::one() } else { Permill::zero() })
+	}
+}
+
+#[derive(Clone)]
+pub struct TestClient;
+
+pub struct RuntimeApi {
+	_inner: TestClient,
+}
+
+impl sp_api::ProvideRuntimeApi<Block> for TestClient {
+	type Api = RuntimeApi;
+	fn runtime_api(&self) -> sp_api::ApiRef<Self::Api> {
+		RuntimeApi { _inner: self.clone() }.into()
+	}
+}
+
+sp_api::mock_impl_runtime_apis! {
+	impl XcmBridgeHubRouterApi<Block> for RuntimeApi {
+		fn delivery_fee_factor() -> FixedU128 {
+			XcmBridgeHubRouter::highest_delivery_fee_factor()
+		}
+
+		fn calculate_fee(
+			destination: VersionedLocation,
+			message_size: u32,
+		) -> Option<VersionedAssets> {
+			let destination = destination.try_into().ok()?;
+			XcmBridgeHubRouter::calculate_fee(destination, message_size).map(Into::into)
+		}
+	}
 }
 
 /// Return test externalities to use in tests.
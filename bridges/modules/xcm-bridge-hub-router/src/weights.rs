@@ -54,6 +54,8 @@ pub trait WeightInfo {
 	fn on_initialize_when_congested() -> Weight;
 	fn report_bridge_status() -> Weight;
 	fn send_message() -> Weight;
+	fn validate_message(s: u32) -> Weight;
+	fn deliver_message(s: u32) -> Weight;
 }
 
 /// Weights for `pallet_xcm_bridge_hub_router` that are generated using one of the Bridge testnets.
@@ -131,6 +133,77 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `validate_message` benchmark before it is used in production.
+	///
+	/// Storage: `XcmBridgeHubRouter::PalletOperatingMode` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(1),
+	/// added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::Bridge` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added:
+	/// 512, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::DeliveryFeeFactors` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::DeliveryFeeFactors` (`max_values`: None, `max_size`: Some(86),
+	/// added: 2561, mode: `MaxEncodedLen`)
+	///
+	/// The `MessageDropped` event is deposited if the message is dropped.
+	///
+	/// The range of component `s` is `[1, 32768]`.
+	fn validate_message(s: u32) -> Weight {
+		Weight::from_parts(14_902_000, 3569)
+			.saturating_add(Weight::from_parts(1_164, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `deliver_message` benchmark before it is used in production.
+	///
+	/// Storage: `XcmBridgeHubRouter::Bridge` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added:
+	/// 512, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::DeliveryFeeFactors` (r:33 w:2)
+	///
+	/// Proof: `XcmBridgeHubRouter::DeliveryFeeFactors` (`max_values`: None, `max_size`: Some(86),
+	/// added: 2561, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::CounterForDeliveryFeeFactors` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::CounterForDeliveryFeeFactors` (`max_values`: Some(1),
+	/// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::FeeFactorAlerts` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorAlerts` (`max_values`: Some(1), `max_size`: Some(8),
+	/// added: 503, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::FeeFactorAlertRaised` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorAlertRaised` (`max_values`: Some(1), `max_size`:
+	/// Some(1), added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Storage: UNKNOWN KEY `0x456d756c617465645369626c696e6758636d704368616e6e656c2e436f6e6765`
+	/// (r:1 w:0)
+	///
+	/// Proof: UNKNOWN KEY `0x456d756c617465645369626c696e6758636d704368616e6e656c2e436f6e6765` (r:1
+	/// w:0)
+	///
+	/// The `DeliveryFeeFactors` are read (up to `MaxTrackedBridges = 16` entries) when the stale
+	/// factor is evicted and when the highest factor is computed. The `DeliveryFeeFactorIncreased`
+	/// and the `DeliveryFeeFactorAlert` events are deposited if the factor is increased.
+	///
+	/// The range of component `s` is `[1, 32768]`.
+	fn deliver_message(s: u32) -> Weight {
+		Weight::from_parts(9_105_000, 7323)
+			.saturating_add(Weight::from_parts(247, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -205,4 +278,75 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `validate_message` benchmark before it is used in production.
+	///
+	/// Storage: `XcmBridgeHubRouter::PalletOperatingMode` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(1),
+	/// added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::Bridge` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added:
+	/// 512, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::DeliveryFeeFactors` (r:1 w:0)
+	///
+	/// Proof: `XcmBridgeHubRouter::DeliveryFeeFactors` (`max_values`: None, `max_size`: Some(86),
+	/// added: 2561, mode: `MaxEncodedLen`)
+	///
+	/// The `MessageDropped` event is deposited if the message is dropped.
+	///
+	/// The range of component `s` is `[1, 32768]`.
+	fn validate_message(s: u32) -> Weight {
+		Weight::from_parts(14_902_000, 3569)
+			.saturating_add(Weight::from_parts(1_164, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `deliver_message` benchmark before it is used in production.
+	///
+	/// Storage: `XcmBridgeHubRouter::Bridge` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added:
+	/// 512, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::DeliveryFeeFactors` (r:33 w:2)
+	///
+	/// Proof: `XcmBridgeHubRouter::DeliveryFeeFactors` (`max_values`: None, `max_size`: Some(86),
+	/// added: 2561, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::CounterForDeliveryFeeFactors` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::CounterForDeliveryFeeFactors` (`max_values`: Some(1),
+	/// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::FeeFactorAlerts` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorAlerts` (`max_values`: Some(1), `max_size`: Some(8),
+	/// added: 503, mode: `MaxEncodedLen`)
+	///
+	/// Storage: `XcmBridgeHubRouter::FeeFactorAlertRaised` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorAlertRaised` (`max_values`: Some(1), `max_size`:
+	/// Some(1), added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Storage: UNKNOWN KEY `0x456d756c617465645369626c696e6758636d704368616e6e656c2e436f6e6765`
+	/// (r:1 w:0)
+	///
+	/// Proof: UNKNOWN KEY `0x456d756c617465645369626c696e6758636d704368616e6e656c2e436f6e6765` (r:1
+	/// w:0)
+	///
+	/// The `DeliveryFeeFactors` are read (up to `MaxTrackedBridges = 16` entries) when the stale
+	/// factor is evicted and when the highest factor is computed. The `DeliveryFeeFactorIncreased`
+	/// and the `DeliveryFeeFactorAlert` events are deposited if the factor is increased.
+	///
+	/// The range of component `s` is `[1, 32768]`.
+	fn deliver_message(s: u32) -> Weight {
+		Weight::from_parts(9_105_000, 7323)
+			.saturating_add(Weight::from_parts(247, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -92,11 +92,13 @@ impl pallet_bridge_messages::Config for TestRuntime {
 	type MaximalOutboundPayloadSize = ConstU32<2048>;
 	type OutboundPayload = Vec<u8>;
 	type InboundPayload = Vec<u8>;
+	type MaxInboundMessageSize = ConstU32<2048>;
 	type InboundRelayer = ();
 	type DeliveryPayments = ();
 	type TargetHeaderChain = TargetHeaderChainAdapter<OnThisChainBridge>;
 	type DeliveryConfirmationPayments = ();
 	type OnMessagesDelivered = ();
+	type MaxDeliveryProofsPerRelayerPerBlock = ();
 	type SourceHeaderChain = SourceHeaderChainAdapter<OnThisChainBridge>;
 	type MessageDispatch = TestMessageDispatch;
 }
@@ -268,7 +270,11 @@ impl MessageDispatch for TestMessageDispatch {
 	fn dispatch(
 		_: DispatchMessage<Self::DispatchPayload>,
 	) -> MessageDispatchResult<Self::DispatchLevelResult> {
-		MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result: () }
+		MessageDispatchResult {
+			unspent_weight: Weight::zero(),
+			dispatch_level_result: (),
+			dispatch_error: None,
+		}
 	}
 }
 
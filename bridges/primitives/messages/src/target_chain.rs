@@ -207,6 +207,10 @@ impl<MessagesProof, DispatchPayload: Decode> MessageDispatch
 	fn dispatch(
 		_: DispatchMessage<Self::DispatchPayload>,
 	) -> MessageDispatchResult<Self::DispatchLevelResult> {
-		MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result: () }
+		MessageDispatchResult {
+			unspent_weight: Weight::zero(),
+			dispatch_level_result: (),
+			dispatch_error: None,
+		}
 	}
 }
@@ -152,6 +152,19 @@ where
 	type Value = Reward;
 }
 
+sp_api::decl_runtime_apis! {
+	/// API for querying rewards of relayers at the chain with the relayers pallet deployed.
+	pub trait BridgeRelayersApi<AccountId, Reward>
+	where
+		AccountId: Codec,
+		Reward: Codec,
+	{
+		/// Returns the total reward that the given relayer may claim at this chain, over all
+		/// lanes and all rewards accounts.
+		fn total_relayer_reward(relayer: AccountId) -> Reward;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -33,4 +33,23 @@ pub struct MessageDispatchResult<DispatchLevelResult> {
 	pub unspent_weight: Weight,
 	/// Fine-grained result of single message dispatch (for better diagnostic purposes)
 	pub dispatch_level_result: DispatchLevelResult,
+	/// Error that has happened during message dispatch, or `None` if message has been
+	/// dispatched successfully.
+	pub dispatch_error: Option<DispatchErrorCode>,
+}
+
+impl<DispatchLevelResult> MessageDispatchResult<DispatchLevelResult> {
+	/// Returns outcome of the message dispatch.
+	pub fn outcome(&self) -> Result<(), DispatchErrorCode> {
+		self.dispatch_error.map_or(Ok(()), Err)
+	}
+}
+
+/// Code of the error that has happened during message dispatch.
+#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, PartialEq, Eq, TypeInfo)]
+pub enum DispatchErrorCode {
+	/// Message payload can't be decoded.
+	InvalidPayload,
+	/// Message has been rejected by the dispatcher.
+	NotDispatched,
 }
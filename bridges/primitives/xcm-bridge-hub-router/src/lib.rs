@@ -21,7 +21,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{FixedU128, RuntimeDebug};
+use sp_runtime::{FixedU128, Permill, RuntimeDebug};
+use xcm::{latest::Location, VersionedAssets, VersionedLocation};
 
 /// Minimal delivery fee factor.
 pub const MINIMAL_DELIVERY_FEE_FACTOR: FixedU128 = FixedU128::from_u32(1);
@@ -33,6 +34,20 @@ pub const MINIMAL_DELIVERY_FEE_FACTOR: FixedU128 = FixedU128::from_u32(1);
 pub trait XcmChannelStatusProvider {
 	/// Returns true if the channel is currently congested.
 	fn is_congested() -> bool;
+
+	/// Returns the current utilization of the channel, which is used to deliver messages to the
+	/// given destination: from zero for the empty queue to one for the full queue. If the
+	/// destination is `None`, the utilization of the most utilized channel is returned.
+	///
+	/// By default, the congested channel is fully utilized and the channel that is not congested
+	/// is not utilized at all.
+	fn utilization(_destination: Option<&Location>) -> Permill {
+		if Self::is_congested() {
+			Permill::one()
+		} else {
+			Permill::zero()
+		}
+	}
 }
 
 impl XcmChannelStatusProvider for () {
@@ -64,3 +79,21 @@ pub enum XcmBridgeHubRouterCall {
 	#[codec(index = 0)]
 	report_bridge_status { bridge_id: H256, is_congested: bool },
 }
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the delivery fees of the `xcm-bridge-hub-router` pallet.
+	pub trait XcmBridgeHubRouterApi {
+		/// Returns the current delivery fee factor. If every bridge has its own factor, the
+		/// highest one is returned.
+		fn delivery_fee_factor() -> FixedU128;
+
+		/// Returns the fees that are charged by the router for sending the message of given size
+		/// to the given destination, or `None` if the message may not be routed there.
+		///
+		/// The fee for delivering the message to the sibling/child bridge hub is not included.
+		fn calculate_fee(
+			destination: VersionedLocation,
+			message_size: u32,
+		) -> Option<VersionedAssets>;
+	}
+}
@@ -902,6 +902,7 @@ impl pallet_nfts::Config for Runtime {
 /// consensus with dynamic fees and back-pressure.
 pub type ToWestendXcmRouterInstance = pallet_xcm_bridge_hub_router::Instance3;
 impl pallet_xcm_bridge_hub_router::Config<ToWestendXcmRouterInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_xcm_bridge_hub_router::WeightInfo<Runtime>;
 
 	type UniversalLocation = xcm_config::UniversalLocation;
@@ -918,6 +919,7 @@ impl pallet_xcm_bridge_hub_router::Config<ToWestendXcmRouterInstance> for Runtim
 		// for running tests with `--feature runtime-benchmarks`
 		EnsureXcm<Equals<xcm_config::bridging::SiblingBridgeHub>>,
 	>;
+	type AdminOrigin = EnsureRoot<AccountId>;
 
 	type ToBridgeHubSender = XcmpQueue;
 	type WithBridgeHubChannel =
@@ -927,7 +929,21 @@ impl pallet_xcm_bridge_hub_router::Config<ToWestendXcmRouterInstance> for Runtim
 		>;
 
 	type ByteFee = xcm_config::bridging::XcmBridgeHubRouterByteFee;
+	type ScaleByteFeeByFactor = ConstBool<true>;
 	type FeeAsset = xcm_config::bridging::XcmBridgeHubRouterFeeAssetId;
+	type AdditionalByteFees = ();
+	type MaxFeeAssets = ConstU32<4>;
+	type HardMessageSizeLimit = ConstU32<{ pallet_xcm_bridge_hub_router::HARD_MESSAGE_SIZE_LIMIT }>;
+	type FeeFactorAlertThreshold = xcm_config::bridging::XcmBridgeHubRouterFeeFactorAlertThreshold;
+	type CongestionUtilizationThreshold = ();
+	type MaxFeeFactorIncreasePerSend =
+		xcm_config::bridging::XcmBridgeHubRouterMaxFeeFactorIncreasePerSend;
+	type MaximumFeeFactor = xcm_config::bridging::XcmBridgeHubRouterMaximumFeeFactor;
+	type ExponentialFeeBase = xcm_config::bridging::XcmBridgeHubRouterExponentialFeeBase;
+	type MessageSizeFeeBase = xcm_config::bridging::XcmBridgeHubRouterMessageSizeFeeBase;
+	type MessageSizeFeeCurve = ();
+	type PerBridgeFeeFactors = ();
+	type MaxTrackedBridges = ConstU32<16>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1017,6 +1033,7 @@ pub type Migrations = (
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	frame_support::migrations::RemovePallet<StateTrieMigrationName, RocksDbWeight>,
+	pallet_xcm_bridge_hub_router::migration::MigrateV0ToV1<Runtime, ToWestendXcmRouterInstance, ()>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -123,4 +123,51 @@ impl<T: frame_system::Config> pallet_xcm_bridge_hub_router::WeightInfo for Weigh
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `validate_message` benchmark before it is used in production.
+	/// Storage: `ToWestendXcmRouter::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `ToWestendXcmRouter::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:2 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ToWestendXcmRouter::Bridge` (r:1 w:0)
+	/// Proof: `ToWestendXcmRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SafeXcmVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SafeXcmVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The `MessageDropped` event is deposited if the message is dropped.
+	/// The range of component `s` is `[1, 32768]`.
+	fn validate_message(s: u32, ) -> Weight {
+		Weight::from_parts(31_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 6388))
+			.saturating_add(Weight::from_parts(1_318, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+	}
+	/// NOTE: this is a placeholder that has not been generated by the benchmarking CLI. It must be
+	/// regenerated using the `deliver_message` benchmark before it is used in production.
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::InboundXcmpSuspended` (r:1 w:0)
+	/// Proof: `XcmpQueue::InboundXcmpSuspended` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ToWestendXcmRouter::Bridge` (r:1 w:1)
+	/// Proof: `ToWestendXcmRouter::Bridge` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `ToWestendXcmRouter::FeeFactorAlerts` (r:1 w:1)
+	/// Proof: `ToWestendXcmRouter::FeeFactorAlerts` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ToWestendXcmRouter::FeeFactorAlertRaised` (r:1 w:1)
+	/// Proof: `ToWestendXcmRouter::FeeFactorAlertRaised` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The `DeliveryFeeFactorIncreased` and the `DeliveryFeeFactorAlert` events are deposited if the factor is increased.
+	/// The range of component `s` is `[1, 32768]`.
+	fn deliver_message(s: u32, ) -> Weight {
+		Weight::from_parts(29_836_000, 0)
+			.saturating_add(Weight::from_parts(0, 6335))
+			.saturating_add(Weight::from_parts(412, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
@@ -533,6 +533,25 @@ pub mod bridging {
 		/// Price of every byte of the Rococo -> Westend message. Can be adjusted via
 		/// governance `set_storage` call.
 		pub storage XcmBridgeHubRouterByteFee: Balance = TransactionByteFee::get();
+		/// Delivery fee factor above which the `pallet-xcm-bridge-hub-router` counts the sent
+		/// messages as sent during severe congestion.
+		pub XcmBridgeHubRouterFeeFactorAlertThreshold: sp_runtime::FixedU128 =
+			sp_runtime::FixedU128::from_u32(16);
+		/// Maximal factor by which a single sent message may increase the delivery fee factor of
+		/// the `pallet-xcm-bridge-hub-router`. Unbounded.
+		pub const XcmBridgeHubRouterMaxFeeFactorIncreasePerSend: sp_runtime::FixedU128 =
+			sp_runtime::FixedU128::from_inner(u128::MAX);
+		/// Maximal value of the delivery fee factor of the `pallet-xcm-bridge-hub-router`.
+		pub const XcmBridgeHubRouterMaximumFeeFactor: sp_runtime::FixedU128 =
+			sp_runtime::FixedU128::from_u32(1_000_000);
+		/// Factor by which the delivery fee factor of the `pallet-xcm-bridge-hub-router` grows
+		/// for every message sent over the congested bridge.
+		pub const XcmBridgeHubRouterExponentialFeeBase: sp_runtime::FixedU128 =
+			pallet_xcm_bridge_hub_router::EXPONENTIAL_FEE_BASE;
+		/// Increase of the delivery fee factor of the `pallet-xcm-bridge-hub-router` for every
+		/// kilobyte of the message sent over the congested bridge.
+		pub const XcmBridgeHubRouterMessageSizeFeeBase: sp_runtime::FixedU128 =
+			pallet_xcm_bridge_hub_router::MESSAGE_SIZE_FEE_BASE;
 
 		pub SiblingBridgeHubParaId: u32 = bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID;
 		pub SiblingBridgeHub: Location = Location::new(1, [Parachain(SiblingBridgeHubParaId::get())]);
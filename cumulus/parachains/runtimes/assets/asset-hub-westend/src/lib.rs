@@ -891,6 +891,7 @@ impl pallet_nfts::Config for Runtime {
 /// consensus with dynamic fees and back-pressure.
 pub type ToRococoXcmRouterInstance = pallet_xcm_bridge_hub_router::Instance1;
 impl pallet_xcm_bridge_hub_router::Config<ToRococoXcmRouterInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_xcm_bridge_hub_router::WeightInfo<Runtime>;
 
 	type UniversalLocation = xcm_config::UniversalLocation;
@@ -907,6 +908,7 @@ impl pallet_xcm_bridge_hub_router::Config<ToRococoXcmRouterInstance> for Runtime
 		// for running tests with `--feature runtime-benchmarks`
 		EnsureXcm<Equals<xcm_config::bridging::SiblingBridgeHub>>,
 	>;
+	type AdminOrigin = EnsureRoot<AccountId>;
 
 	type ToBridgeHubSender = XcmpQueue;
 	type WithBridgeHubChannel =
@@ -916,7 +918,21 @@ impl pallet_xcm_bridge_hub_router::Config<ToRococoXcmRouterInstance> for Runtime
 		>;
 
 	type ByteFee = xcm_config::bridging::XcmBridgeHubRouterByteFee;
+	type ScaleByteFeeByFactor = ConstBool<true>;
 	type FeeAsset = xcm_config::bridging::XcmBridgeHubRouterFeeAssetId;
+	type AdditionalByteFees = ();
+	type MaxFeeAssets = ConstU32<4>;
+	type HardMessageSizeLimit = ConstU32<{ pallet_xcm_bridge_hub_router::HARD_MESSAGE_SIZE_LIMIT }>;
+	type FeeFactorAlertThreshold = xcm_config::bridging::XcmBridgeHubRouterFeeFactorAlertThreshold;
+	type CongestionUtilizationThreshold = ();
+	type MaxFeeFactorIncreasePerSend =
+		xcm_config::bridging::XcmBridgeHubRouterMaxFeeFactorIncreasePerSend;
+	type MaximumFeeFactor = xcm_config::bridging::XcmBridgeHubRouterMaximumFeeFactor;
+	type ExponentialFeeBase = xcm_config::bridging::XcmBridgeHubRouterExponentialFeeBase;
+	type MessageSizeFeeBase = xcm_config::bridging::XcmBridgeHubRouterMessageSizeFeeBase;
+	type MessageSizeFeeCurve = ();
+	type PerBridgeFeeFactors = ();
+	type MaxTrackedBridges = ConstU32<16>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1016,6 +1032,8 @@ pub type Migrations = (
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+	// unreleased
+	pallet_xcm_bridge_hub_router::migration::MigrateV0ToV1<Runtime, ToRococoXcmRouterInstance, ()>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
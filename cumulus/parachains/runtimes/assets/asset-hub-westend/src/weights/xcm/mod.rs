@@ -16,7 +16,11 @@
 mod pallet_xcm_benchmarks_fungible;
 mod pallet_xcm_benchmarks_generic;
 
-use crate::{xcm_config::MaxAssetsIntoHolding, Runtime};
+use crate::{
+	xcm_config::{bridging::to_rococo::RococoNetwork, MaxAssetsIntoHolding},
+	Runtime, ToRococoXcmRouter,
+};
+use codec::Encode;
 use frame_support::weights::Weight;
 use pallet_xcm_benchmarks_fungible::WeightInfo as XcmFungibleWeight;
 use pallet_xcm_benchmarks_generic::WeightInfo as XcmGeneric;
@@ -55,6 +59,16 @@ impl WeighAssets for Assets {
 	}
 }
 
+/// Returns the weight of routing the message to the given destination over the bridge with
+/// Rococo. The size of the message is approximated by the size of the forwarded program.
+fn bridge_routing_weight(dest: &Location, xcm: &Xcm<()>) -> Weight {
+	match dest.unpack() {
+		(2, [GlobalConsensus(network), ..]) if *network == RococoNetwork::get() =>
+			ToRococoXcmRouter::send_message_weight(xcm.encoded_size() as u32),
+		_ => Weight::zero(),
+	}
+}
+
 pub struct AssetHubWestendXcmWeight<Call>(core::marker::PhantomData<Call>);
 impl<Call> XcmWeightInfo<Call> for AssetHubWestendXcmWeight<Call> {
 	fn withdraw_asset(assets: &Assets) -> Weight {
@@ -77,8 +91,10 @@ impl<Call> XcmWeightInfo<Call> for AssetHubWestendXcmWeight<Call> {
 	fn transfer_asset(assets: &Assets, _dest: &Location) -> Weight {
 		assets.weigh_assets(XcmFungibleWeight::<Runtime>::transfer_asset())
 	}
-	fn transfer_reserve_asset(assets: &Assets, _dest: &Location, _xcm: &Xcm<()>) -> Weight {
-		assets.weigh_assets(XcmFungibleWeight::<Runtime>::transfer_reserve_asset())
+	fn transfer_reserve_asset(assets: &Assets, dest: &Location, xcm: &Xcm<()>) -> Weight {
+		assets
+			.weigh_assets(XcmFungibleWeight::<Runtime>::transfer_reserve_asset())
+			.saturating_add(bridge_routing_weight(dest, xcm))
 	}
 	fn transact(
 		_origin_type: &OriginKind,
@@ -116,21 +132,27 @@ impl<Call> XcmWeightInfo<Call> for AssetHubWestendXcmWeight<Call> {
 	fn deposit_asset(assets: &AssetFilter, _dest: &Location) -> Weight {
 		assets.weigh_assets(XcmFungibleWeight::<Runtime>::deposit_asset())
 	}
-	fn deposit_reserve_asset(assets: &AssetFilter, _dest: &Location, _xcm: &Xcm<()>) -> Weight {
-		assets.weigh_assets(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
+	fn deposit_reserve_asset(assets: &AssetFilter, dest: &Location, xcm: &Xcm<()>) -> Weight {
+		assets
+			.weigh_assets(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
+			.saturating_add(bridge_routing_weight(dest, xcm))
 	}
 	fn exchange_asset(_give: &AssetFilter, _receive: &Assets, _maximal: &bool) -> Weight {
 		Weight::MAX
 	}
 	fn initiate_reserve_withdraw(
 		assets: &AssetFilter,
-		_reserve: &Location,
-		_xcm: &Xcm<()>,
+		reserve: &Location,
+		xcm: &Xcm<()>,
 	) -> Weight {
-		assets.weigh_assets(XcmFungibleWeight::<Runtime>::initiate_reserve_withdraw())
-	}
-	fn initiate_teleport(assets: &AssetFilter, _dest: &Location, _xcm: &Xcm<()>) -> Weight {
-		assets.weigh_assets(XcmFungibleWeight::<Runtime>::initiate_teleport())
+		assets
+			.weigh_assets(XcmFungibleWeight::<Runtime>::initiate_reserve_withdraw())
+			.saturating_add(bridge_routing_weight(reserve, xcm))
+	}
+	fn initiate_teleport(assets: &AssetFilter, dest: &Location, xcm: &Xcm<()>) -> Weight {
+		assets
+			.weigh_assets(XcmFungibleWeight::<Runtime>::initiate_teleport())
+			.saturating_add(bridge_routing_weight(dest, xcm))
 	}
 	fn report_holding(_response_info: &QueryResponseInfo, _assets: &AssetFilter) -> Weight {
 		XcmGeneric::<Runtime>::report_holding()
@@ -157,6 +157,10 @@ impl MessageBridge for WithRococoBulletinMessageBridge {
 pub type ToRococoBulletinMaximalOutboundPayloadSize =
 	messages::source::FromThisChainMaximalOutboundPayloadSize<WithRococoBulletinMessageBridge>;
 
+/// Maximal inbound payload size of RococoBulletin -> BridgeHubRococo messages.
+pub type FromRococoBulletinMaximalInboundPayloadSize =
+	messages::target::FromBridgedChainMaximalInboundPayloadSize<WithRococoBulletinMessageBridge>;
+
 /// RococoBulletin chain from message lane point of view.
 #[derive(RuntimeDebug, Clone, Copy)]
 pub struct RococoBulletin;
@@ -198,6 +202,7 @@ impl pallet_bridge_messages::Config<WithRococoBulletinMessagesInstance> for Runt
 	type OutboundPayload = XcmAsPlainPayload;
 
 	type InboundPayload = XcmAsPlainPayload;
+	type MaxInboundMessageSize = FromRococoBulletinMaximalInboundPayloadSize;
 	type InboundRelayer = AccountId;
 	type DeliveryPayments = ();
 
@@ -208,6 +213,7 @@ impl pallet_bridge_messages::Config<WithRococoBulletinMessagesInstance> for Runt
 	type MessageDispatch =
 		XcmBlobMessageDispatch<FromRococoBulletinMessageBlobDispatcher, Self::WeightInfo, ()>;
 	type OnMessagesDelivered = OnMessagesDeliveredFromRococoBulletin;
+	type MaxDeliveryProofsPerRelayerPerBlock = ();
 }
 
 /// Add support for the export and dispatch of XCM programs.
@@ -166,6 +166,10 @@ impl MessageBridge for WithBridgeHubWestendMessageBridge {
 pub type ToBridgeHubWestendMaximalOutboundPayloadSize =
 	messages::source::FromThisChainMaximalOutboundPayloadSize<WithBridgeHubWestendMessageBridge>;
 
+/// Maximal inbound payload size of BridgeHubWestend -> BridgeHubRococo messages.
+pub type FromBridgeHubWestendMaximalInboundPayloadSize =
+	messages::target::FromBridgedChainMaximalInboundPayloadSize<WithBridgeHubWestendMessageBridge>;
+
 /// BridgeHubWestend chain from message lane point of view.
 #[derive(RuntimeDebug, Clone, Copy)]
 pub struct BridgeHubWestend;
@@ -205,6 +209,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubWestendMessagesInstance> for Ru
 	type OutboundPayload = XcmAsPlainPayload;
 
 	type InboundPayload = XcmAsPlainPayload;
+	type MaxInboundMessageSize = FromBridgeHubWestendMaximalInboundPayloadSize;
 	type InboundRelayer = AccountId;
 	type DeliveryPayments = ();
 
@@ -225,6 +230,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubWestendMessagesInstance> for Ru
 		>,
 	>;
 	type OnMessagesDelivered = OnMessagesDeliveredFromWestend;
+	type MaxDeliveryProofsPerRelayerPerBlock = ();
 }
 
 /// Add support for the export and dispatch of XCM programs.
@@ -1041,6 +1041,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_relayers::BridgeRelayersApi<Block, AccountId, Balance> for Runtime {
+		fn total_relayer_reward(relayer: AccountId) -> Balance {
+			BridgeRelayers::total_relayer_reward(&relayer)
+		}
+	}
+
 	// This is exposed by BridgeHubRococo
 	impl bp_bridge_hub_westend::FromBridgeHubWestendInboundLaneApi<Block> for Runtime {
 		fn message_details(
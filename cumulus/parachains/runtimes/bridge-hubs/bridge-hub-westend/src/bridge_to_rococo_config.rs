@@ -171,6 +171,10 @@ impl MessageBridge for WithBridgeHubRococoMessageBridge {
 type ToBridgeHubRococoMaximalOutboundPayloadSize =
 	messages::source::FromThisChainMaximalOutboundPayloadSize<WithBridgeHubRococoMessageBridge>;
 
+/// Maximal inbound payload size of BridgeHubRococo -> BridgeHubWestend messages.
+type FromBridgeHubRococoMaximalInboundPayloadSize =
+	messages::target::FromBridgedChainMaximalInboundPayloadSize<WithBridgeHubRococoMessageBridge>;
+
 /// BridgeHubRococo chain from message lane point of view.
 #[derive(RuntimeDebug, Clone, Copy)]
 pub struct BridgeHubRococo;
@@ -246,6 +250,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubRococoMessagesInstance> for Run
 	type OutboundPayload = XcmAsPlainPayload;
 
 	type InboundPayload = XcmAsPlainPayload;
+	type MaxInboundMessageSize = FromBridgeHubRococoMaximalInboundPayloadSize;
 	type InboundRelayer = AccountId;
 	type DeliveryPayments = ();
 
@@ -266,6 +271,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubRococoMessagesInstance> for Run
 		>,
 	>;
 	type OnMessagesDelivered = OnMessagesDelivered;
+	type MaxDeliveryProofsPerRelayerPerBlock = ();
 }
 
 /// Add support for the export and dispatch of XCM programs.
@@ -791,6 +791,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_relayers::BridgeRelayersApi<Block, AccountId, Balance> for Runtime {
+		fn total_relayer_reward(relayer: AccountId) -> Balance {
+			BridgeRelayers::total_relayer_reward(&relayer)
+		}
+	}
+
 	impl bp_bridge_hub_rococo::FromBridgeHubRococoInboundLaneApi<Block> for Runtime {
 		fn message_details(
 			lane: bp_messages::LaneId,
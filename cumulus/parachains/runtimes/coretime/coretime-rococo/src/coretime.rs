@@ -227,6 +227,7 @@ impl pallet_broker::Config for Runtime {
 	type TimeslicePeriod = ConstU32<80>;
 	type MaxLeasedCores = ConstU32<50>;
 	type MaxReservedCores = ConstU32<10>;
+	type MaxDropBatchSize = ConstU32<16>;
 	type Coretime = CoretimeAllocator;
 	type ConvertBalance = sp_runtime::traits::Identity;
 	type WeightInfo = weights::pallet_broker::WeightInfo<Runtime>;
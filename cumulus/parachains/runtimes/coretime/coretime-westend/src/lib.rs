@@ -601,10 +601,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_broker::runtime_api::BrokerApi<Block, Balance> for Runtime {
 		fn sale_price() -> Result<Balance, DispatchError> {
 			Broker::current_price()
 		}
+
+		fn can_renew(
+			core: pallet_broker::CoreIndex,
+		) -> Result<(), pallet_broker::RenewalIneligibilityReason> {
+			Broker::can_renew(core)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
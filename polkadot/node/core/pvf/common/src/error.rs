@@ -153,4 +153,10 @@ pub enum InternalValidationError {
 	/// Some non-deterministic preparation error occurred.
 	#[error("validation: prepare: {0}")]
 	NonDeterministicPrepareError(PrepareError),
+	/// The job was terminated to free up a worker for a job with a higher priority.
+	#[error("validation: the job was preempted by a job with a higher priority")]
+	Preempted,
+	/// The execution queue is paused for maintenance and doesn't accept new jobs.
+	#[error("validation: the execution queue is paused")]
+	QueuePaused,
 }
@@ -23,4 +23,8 @@
 mod queue;
 mod worker_interface;
 
-pub use queue::{start, FromQueue, PendingExecutionRequest, ToQueue};
+pub use queue::{
+	derive_worker_capacity, start, threads_per_job_hint, DefaultExecuteErrorClassifier,
+	ExecuteErrorClassifier, ExecuteJobFailure, FromQueue, PendingExecutionRequest, PendingJobDump,
+	QueueStateDump, ToQueue, WarmWorkersTarget, Worker, WorkerDump,
+};
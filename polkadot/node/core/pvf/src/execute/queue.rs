@@ -20,41 +20,80 @@ use super::worker_interface::{Error as WorkerInterfaceError, Response as WorkerI
 use crate::{
 	artifacts::{ArtifactId, ArtifactPathId},
 	host::ResultSender,
-	metrics::Metrics,
+	metrics::{ExecuteOutcome, Metrics, WorkerSpawnReason},
+	spawn_limiter::{SpawnLimiter, SpawnPermit},
 	worker_interface::{IdleWorker, WorkerHandle},
-	InvalidCandidate, PossiblyInvalidError, ValidationError, LOG_TARGET,
+	InvalidCandidate, PossiblyInvalidError, Priority, ValidationError, LOG_TARGET,
 };
 use futures::{
 	channel::{mpsc, oneshot},
-	future::BoxFuture,
+	future::{BoxFuture, Shared},
 	stream::{FuturesUnordered, StreamExt as _},
 	Future, FutureExt,
 };
 use polkadot_node_core_pvf_common::{
+	error::InternalValidationError,
 	execute::{JobResponse, WorkerError, WorkerResponse},
 	SecurityStatus,
 };
-use polkadot_primitives::{ExecutorParams, ExecutorParamsHash};
-use slotmap::HopSlotMap;
+use codec::{Decode, Encode, Input, Output};
+use polkadot_parachain_primitives::primitives::ValidationCodeHash;
+use polkadot_primitives::{
+	executor_params::DEFAULT_NATIVE_STACK_MAX, ExecutorParam, ExecutorParams, ExecutorParamsHash,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use slotmap::{HopSlotMap, Key as _, KeyData};
 use std::{
-	collections::VecDeque,
-	fmt,
-	path::PathBuf,
+	collections::{HashMap, HashSet, VecDeque},
+	fmt, io,
+	path::{Path, PathBuf},
+	sync::Arc,
 	time::{Duration, Instant},
 };
 
 /// The amount of time a job for which the queue does not have a compatible worker may wait in the
 /// queue. After that time passes, the queue will kill the first worker which becomes idle to
-/// re-spawn a new worker to execute the job immediately.
+/// re-spawn a new worker to execute the job immediately. Until then, the last idle worker is never
+/// killed to be re-spawned, so that the jobs compatible with it don't pay the spawn latency.
 /// To make any sense and not to break things, the value should be greater than minimal execution
 /// timeout in use, and less than the block time.
 const MAX_KEEP_WAITING: Duration = Duration::from_secs(4);
 
-slotmap::new_key_type! { struct Worker; }
+slotmap::new_key_type! {
+	/// Identifies a running execute worker, as seen in the [`QueueStateDump`].
+	pub struct Worker;
+}
+
+impl Encode for Worker {
+	fn size_hint(&self) -> usize {
+		self.data().as_ffi().size_hint()
+	}
+
+	fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+		self.data().as_ffi().encode_to(dest)
+	}
+}
+
+impl Decode for Worker {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+		Ok(KeyData::from_ffi(u64::decode(input)?).into())
+	}
+}
 
 #[derive(Debug)]
 pub enum ToQueue {
 	Enqueue { artifact: ArtifactPathId, pending_execution_request: PendingExecutionRequest },
+	/// Requests a snapshot of the queue state, for debugging.
+	DumpState { reply_to: oneshot::Sender<QueueStateDump> },
+	/// Requests the given worker to be removed, e.g. if it is suspected to be compromised. An
+	/// idle worker is killed immediately, a busy one is killed once its current job concludes.
+	RemoveWorker(Worker),
+	/// Stops accepting new jobs, e.g. before the worker binary is replaced. The jobs already
+	/// accepted are still executed, and `drained` is notified once all of them have concluded and
+	/// all the workers are idle. New jobs fail with `InternalValidationError::QueuePaused`.
+	Pause { drained: oneshot::Sender<()> },
+	/// Resumes accepting new jobs after a pause.
+	Resume,
 }
 
 /// A response from queue.
@@ -70,14 +109,123 @@ pub struct PendingExecutionRequest {
 	pub exec_timeout: Duration,
 	pub params: Vec<u8>,
 	pub executor_params: ExecutorParams,
+	pub priority: Priority,
 	pub result_tx: ResultSender,
 }
 
+/// A consistent snapshot of the queue state.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct QueueStateDump {
+	/// The jobs with the critical priority that are waiting for a worker, the eldest first.
+	pub pending_critical: Vec<PendingJobDump>,
+	/// The jobs with the normal priority that are waiting for a worker, the eldest first.
+	pub pending_normal: Vec<PendingJobDump>,
+	/// The running workers.
+	pub workers: Vec<WorkerDump>,
+	/// The number of spawning but not yet spawned workers.
+	pub spawn_inflight: u32,
+	/// Whether the queue is paused and doesn't accept new jobs.
+	pub paused: bool,
+}
+
+/// A job waiting for a worker, as seen in the [`QueueStateDump`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PendingJobDump {
+	/// The hash of the validation code the job executes.
+	pub code_hash: ValidationCodeHash,
+	/// The hash of the executor parameters the job requires.
+	pub executor_params_hash: ExecutorParamsHash,
+	/// The time the job has been waiting for, in milliseconds.
+	pub waiting_ms: u64,
+}
+
+/// A running worker, as seen in the [`QueueStateDump`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct WorkerDump {
+	/// The identifier of the worker within the queue.
+	pub worker: Worker,
+	/// The process id of the worker.
+	pub pid: u32,
+	/// The hash of the executor parameters the worker was spawned with.
+	pub executor_params_hash: ExecutorParamsHash,
+	/// Whether the worker is executing a job.
+	pub busy: bool,
+}
+
+/// The raw outcome of an execution job that did not succeed.
+#[derive(Debug)]
+pub enum ExecuteJobFailure {
+	/// The worker reported the candidate as invalid.
+	InvalidCandidate(String),
+	/// The worker failed to construct the runtime from the artifact.
+	RuntimeConstruction(String),
+	/// The communication with the worker exceeded the hard limit.
+	HardTimeout,
+	/// An I/O error happened during communication with the worker.
+	CommunicationErr(io::Error),
+	/// The worker reported an error, either of its own or of the job.
+	WorkerError(WorkerError),
+	/// An internal error happened on the host side.
+	InternalError(InternalValidationError),
+}
+
+/// Determines the executor parameters the warm workers are spawned with.
+#[derive(Clone, Debug, Default)]
+pub enum WarmWorkersTarget {
+	/// The executor parameters of the most recently assigned job.
+	#[default]
+	MostRecentlyUsed,
+	/// The given executor parameters, e.g. the ones the network is about to switch to. The jobs
+	/// with the current parameters are still executed on the demand-spawned workers.
+	Upcoming(ExecutorParams),
+}
+
+/// Determines which error is reported to the host for a failed execution job. In particular, it
+/// decides whether the failure is treated as definitely invalid or may be retried.
+///
+/// Whatever the classification is, the worker is still killed or reused and the artifact is
+/// still removed according to the raw outcome.
+pub trait ExecuteErrorClassifier: fmt::Debug + Send + Sync {
+	/// Maps the raw outcome of a failed execution job to the error reported to the host.
+	fn classify(&self, failure: ExecuteJobFailure) -> ValidationError;
+}
+
+/// The default classification of failed execution jobs.
+#[derive(Debug, Default)]
+pub struct DefaultExecuteErrorClassifier;
+
+impl ExecuteErrorClassifier for DefaultExecuteErrorClassifier {
+	fn classify(&self, failure: ExecuteJobFailure) -> ValidationError {
+		match failure {
+			ExecuteJobFailure::InvalidCandidate(err) =>
+				ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err)),
+			ExecuteJobFailure::RuntimeConstruction(err) =>
+				ValidationError::PossiblyInvalid(PossiblyInvalidError::RuntimeConstruction(err)),
+			ExecuteJobFailure::InternalError(err) |
+			ExecuteJobFailure::WorkerError(WorkerError::InternalError(err)) =>
+				ValidationError::Internal(err),
+			// Either the worker or the job timed out. Treated as definitely-invalid, because if we
+			// timed out, there's no time left for a retry.
+			ExecuteJobFailure::HardTimeout |
+			ExecuteJobFailure::WorkerError(WorkerError::JobTimedOut) =>
+				ValidationError::Invalid(InvalidCandidate::HardTimeout),
+			// "Maybe invalid" errors (will retry).
+			ExecuteJobFailure::CommunicationErr(_err) =>
+				ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousWorkerDeath),
+			ExecuteJobFailure::WorkerError(WorkerError::JobDied { err, .. }) =>
+				ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousJobDeath(err)),
+			ExecuteJobFailure::WorkerError(WorkerError::JobError(err)) =>
+				ValidationError::PossiblyInvalid(PossiblyInvalidError::JobError(err.to_string())),
+		}
+	}
+}
+
 struct ExecuteJob {
 	artifact: ArtifactPathId,
 	exec_timeout: Duration,
 	params: Vec<u8>,
 	executor_params: ExecutorParams,
+	priority: Priority,
 	result_tx: ResultSender,
 	waiting_since: Instant,
 }
@@ -86,6 +234,8 @@ struct WorkerData {
 	idle: Option<IdleWorker>,
 	handle: WorkerHandle,
 	executor_params_hash: ExecutorParamsHash,
+	/// The priority of the job the worker is executing, if any.
+	job_priority: Option<Priority>,
 }
 
 impl fmt::Debug for WorkerData {
@@ -103,6 +253,9 @@ struct Workers {
 
 	/// The maximum number of workers queue can have at once.
 	capacity: usize,
+
+	/// The number of workers the queue keeps running, even if there are no jobs for them.
+	min_warm: usize,
 }
 
 impl Workers {
@@ -110,6 +263,12 @@ impl Workers {
 		self.spawn_inflight + self.running.len() < self.capacity
 	}
 
+	/// Returns true if the queue has less workers (including the spawning ones) than the
+	/// configured minimum of warm workers.
+	fn needs_warm_up(&self) -> bool {
+		self.spawn_inflight + self.running.len() < self.min_warm
+	}
+
 	fn find_available(&self, executor_params_hash: ExecutorParamsHash) -> Option<Worker> {
 		self.running.iter().find_map(|d| {
 			if d.1.idle.is_some() && d.1.executor_params_hash == executor_params_hash {
@@ -120,6 +279,10 @@ impl Workers {
 		})
 	}
 
+	fn count_idle(&self) -> usize {
+		self.running.values().filter(|data| data.idle.is_some()).count()
+	}
+
 	fn find_idle(&self) -> Option<Worker> {
 		self.running
 			.iter()
@@ -135,15 +298,30 @@ impl Workers {
 }
 
 enum QueueEvent {
-	Spawn(IdleWorker, WorkerHandle, ExecuteJob),
+	Spawn(IdleWorker, WorkerHandle, ExecuteJob, WorkerSpawnReason),
+	/// A warm worker has been spawned without a job to execute.
+	SpawnWarm(IdleWorker, WorkerHandle, ExecutorParams),
 	StartWork(
 		Worker,
 		Result<WorkerInterfaceResponse, WorkerInterfaceError>,
 		ArtifactId,
+		Priority,
 		ResultSender,
 	),
+	/// The result of a job has been sent after the host acknowledged removal of the artifact.
+	ArtifactRemoved(ArtifactId),
+	/// A critical job may have waited long enough to preempt a running job.
+	PreemptionCheck,
+	/// A spawn permit may be acquired, after the queue has deferred a spawn for the lack of it.
+	SpawnPermitReleased,
+	/// A job, for which the last idle worker has been kept, may have waited long enough to kill
+	/// that worker.
+	KeepWaitingCheck,
 }
 
+/// The host's acknowledgment of an artifact removal, shared by all the jobs waiting for it.
+type ArtifactRemoval = Shared<oneshot::Receiver<()>>;
+
 type Mux = FuturesUnordered<BoxFuture<'static, QueueEvent>>;
 
 struct Queue {
@@ -158,13 +336,45 @@ struct Queue {
 	program_path: PathBuf,
 	cache_path: PathBuf,
 	spawn_timeout: Duration,
+	/// The spawn timeout used instead of `spawn_timeout` for jobs with the critical priority.
+	critical_spawn_timeout: Duration,
+	/// The source of seeds for the backoffs of the failed worker spawns.
+	spawn_retry_rng: StdRng,
+	/// If set, a critical job which waits for a worker longer than that may kill a running job
+	/// with the normal priority to free up a worker slot.
+	preemption_threshold: Option<Duration>,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
+	error_classifier: Arc<dyn ExecuteErrorClassifier>,
 
 	/// The queue of jobs that are waiting for a worker to pick up.
 	queue: VecDeque<ExecuteJob>,
 	workers: Workers,
+	/// The limit of concurrent worker spawns, shared with the prepare queue.
+	spawn_limiter: SpawnLimiter,
+	/// Whether the queue is subscribed to a release of a spawn permit.
+	awaiting_spawn_permit: bool,
+	/// Whether a re-check of the job, for which the last idle worker has been kept, is scheduled.
+	awaiting_keep_waiting_check: bool,
+	/// The executor parameters of the most recently assigned job. Unless the warm workers target
+	/// other parameters, they are spawned with these ones.
+	last_executor_params: Option<ExecutorParams>,
+	/// Determines the executor parameters the warm workers are spawned with.
+	warm_workers_target: WarmWorkersTarget,
 	mux: Mux,
+
+	/// Artifact removals requested from the host and not yet acknowledged.
+	artifact_removals: HashMap<ArtifactId, ArtifactRemoval>,
+	/// Workers which have been killed to free up a slot for a critical job. Their jobs are
+	/// reported as preempted once they conclude.
+	preempted: HashSet<Worker>,
+	/// Busy workers requested to be removed. They are killed once their jobs conclude.
+	retiring: HashSet<Worker>,
+	/// Whether the queue is paused for maintenance. The paused queue rejects new jobs, but
+	/// executes the jobs it has already accepted.
+	paused: bool,
+	/// The subscribers waiting for the paused queue to conclude all its jobs.
+	drain_waiters: Vec<oneshot::Sender<()>>,
 }
 
 impl Queue {
@@ -173,9 +383,16 @@ impl Queue {
 		program_path: PathBuf,
 		cache_path: PathBuf,
 		worker_capacity: usize,
+		min_warm_workers: usize,
+		warm_workers_target: WarmWorkersTarget,
+		spawn_limiter: SpawnLimiter,
 		spawn_timeout: Duration,
+		critical_spawn_timeout: Duration,
+		spawn_retry_seed: Option<u64>,
+		preemption_threshold: Option<Duration>,
 		node_version: Option<String>,
 		security_status: SecurityStatus,
+		error_classifier: Arc<dyn ExecuteErrorClassifier>,
 		to_queue_rx: mpsc::Receiver<ToQueue>,
 		from_queue_tx: mpsc::UnboundedSender<FromQueue>,
 	) -> Self {
@@ -184,8 +401,13 @@ impl Queue {
 			program_path,
 			cache_path,
 			spawn_timeout,
+			critical_spawn_timeout,
+			spawn_retry_rng: spawn_retry_seed
+				.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64),
+			preemption_threshold,
 			node_version,
 			security_status,
+			error_classifier,
 			to_queue_rx,
 			from_queue_tx,
 			queue: VecDeque::new(),
@@ -194,7 +416,18 @@ impl Queue {
 				running: HopSlotMap::with_capacity_and_key(10),
 				spawn_inflight: 0,
 				capacity: worker_capacity,
+				min_warm: min_warm_workers,
 			},
+			spawn_limiter,
+			awaiting_spawn_permit: false,
+			awaiting_keep_waiting_check: false,
+			last_executor_params: None,
+			warm_workers_target,
+			artifact_removals: HashMap::new(),
+			preempted: HashSet::new(),
+			retiring: HashSet::new(),
+			paused: false,
+			drain_waiters: Vec::new(),
 		}
 	}
 
@@ -212,19 +445,85 @@ impl Queue {
 			}
 
 			purge_dead(&self.metrics, &mut self.workers).await;
+			self.maintain_warm_workers();
+			self.notify_if_drained();
+		}
+	}
+
+	/// Spawns idle workers with the targeted executor parameters until the queue has the
+	/// configured minimum of warm workers, as long as it can afford them.
+	fn maintain_warm_workers(&mut self) {
+		// The paused queue doesn't start new workers, unless they are needed for accepted jobs
+		if self.paused {
+			return
+		}
+		let executor_params =
+			if let Some(params) = self.warm_executor_params() { params } else { return };
+
+		while self.workers.needs_warm_up() && self.workers.can_afford_one_more() {
+			let permit =
+				if let Some(permit) = self.try_acquire_spawn_permit() { permit } else { return };
+			spawn_warm_worker(self, executor_params.clone(), permit);
+		}
+	}
+
+	/// Returns the executor parameters the warm workers are spawned with, if known.
+	fn warm_executor_params(&self) -> Option<ExecutorParams> {
+		match &self.warm_workers_target {
+			WarmWorkersTarget::MostRecentlyUsed => self.last_executor_params.clone(),
+			WarmWorkersTarget::Upcoming(params) => Some(params.clone()),
+		}
+	}
+
+	/// Notifies the subscribers waiting for the paused queue to drain, once it has no jobs left
+	/// and all its workers are idle.
+	fn notify_if_drained(&mut self) {
+		if !self.paused || self.drain_waiters.is_empty() {
+			return
+		}
+		let is_drained = self.queue.is_empty() &&
+			self.workers.spawn_inflight == 0 &&
+			self.workers.running.values().all(|data| data.idle.is_some());
+		if !is_drained {
+			return
+		}
+
+		gum::info!(target: LOG_TARGET, "the paused execution queue has drained");
+		for waiter in self.drain_waiters.drain(..) {
+			// The subscriber may have gone away, that's fine
+			let _ = waiter.send(());
 		}
 	}
 
+	/// Takes a permit to spawn a worker. If the shared limit of concurrent spawns is reached,
+	/// subscribes to a release of a permit, so that the deferred spawn is retried.
+	fn try_acquire_spawn_permit(&mut self) -> Option<SpawnPermit> {
+		let permit = self.spawn_limiter.try_acquire();
+		if permit.is_none() && !self.awaiting_spawn_permit {
+			gum::debug!(target: LOG_TARGET, "deferring an execute worker spawn");
+			self.awaiting_spawn_permit = true;
+			self.mux.push(
+				self.spawn_limiter
+					.notify_on_release()
+					.map(|_| QueueEvent::SpawnPermitReleased)
+					.boxed(),
+			);
+		}
+		permit
+	}
+
 	/// Tries to assign a job in the queue to a worker. If an idle worker is provided, it does its
 	/// best to find a job with a compatible execution environment unless there are jobs in the
 	/// queue waiting too long. In that case, it kills an existing idle worker and spawns a new
-	/// one. It may spawn an additional worker if that is affordable.
+	/// one. It may spawn an additional worker if that is affordable. The last idle worker is only
+	/// killed if the job has been waiting for longer than [`MAX_KEEP_WAITING`].
 	/// If all the workers are busy or the queue is empty, it does nothing.
 	/// Should be called every time a new job arrives to the queue or a job finishes.
 	fn try_assign_next_job(&mut self, finished_worker: Option<Worker>) {
 		// New jobs are always pushed to the tail of the queue; the one at its head is always
 		// the eldest one.
 		let eldest = if let Some(eldest) = self.queue.get(0) { eldest } else { return };
+		let eldest_waited = eldest.waiting_since.elapsed();
 
 		// By default, we're going to execute the eldest job on any worker slot available, even if
 		// we have to kill and re-spawn a worker
@@ -233,7 +532,7 @@ impl Queue {
 
 		// But if we're not pressed for time, we can try to find a better job-worker pair not
 		// requiring the expensive kill-spawn operation
-		if eldest.waiting_since.elapsed() < MAX_KEEP_WAITING {
+		if eldest_waited < MAX_KEEP_WAITING {
 			if let Some(finished_worker) = finished_worker {
 				if let Some(worker_data) = self.workers.running.get(finished_worker) {
 					for (i, job) in self.queue.iter().enumerate() {
@@ -251,19 +550,51 @@ impl Queue {
 			worker = self.workers.find_available(self.queue[job_index].executor_params.hash());
 		}
 
+		let mut spawn_reason = WorkerSpawnReason::CapacityGrowth;
+		let mut spawn_permit = None;
+		let mut keep_last_idle = false;
 		if worker.is_none() {
-			if let Some(idle) = self.workers.find_idle() {
+			// Whatever the reason is, a worker is not killed to be re-spawned unless the spawn
+			// fits into the limit of concurrent spawns shared with the prepare queue
+			spawn_permit = self.try_acquire_spawn_permit();
+			if spawn_permit.is_none() {
+				return
+			}
+
+			// Warm workers are not retired if the job may be executed on an extra worker
+			let keep_warm = self.workers.running.len() <= self.workers.min_warm &&
+				self.workers.can_afford_one_more();
+			// Neither is the last idle worker, unless the job has been waiting for too long
+			keep_last_idle = eldest_waited < MAX_KEEP_WAITING && self.workers.count_idle() == 1;
+			if let Some(idle) = self.workers.find_idle().filter(|_| !keep_warm && !keep_last_idle) {
 				// No available workers of required type but there are some idle ones of other
 				// types, have to kill one and re-spawn with the correct type
 				if self.workers.running.remove(idle).is_some() {
 					self.metrics.execute_worker().on_retired();
 				}
+				spawn_reason = WorkerSpawnReason::RespawnAfterKill;
 			}
 		}
 
 		if worker.is_none() && !self.workers.can_afford_one_more() {
-			// Bad luck, no worker slot can be used to execute the job
-			return
+			// Bad luck, no worker slot can be used to execute the job, unless a starving critical
+			// job may take a slot of a running job with the normal priority
+			if let Some(critical_job_index) = self.preempt_for_starving_critical_job() {
+				(job_index, spawn_reason) =
+					(critical_job_index, WorkerSpawnReason::RespawnAfterKill);
+			} else {
+				if keep_last_idle && !self.awaiting_keep_waiting_check {
+					// Re-check the queue once the job has waited too long, even if nothing else
+					// happens
+					self.awaiting_keep_waiting_check = true;
+					self.mux.push(
+						futures_timer::Delay::new(MAX_KEEP_WAITING - eldest_waited)
+							.map(|_| QueueEvent::KeepWaitingCheck)
+							.boxed(),
+					);
+				}
+				return
+			}
 		}
 
 		let job = self.queue.remove(job_index).expect("Job is just checked to be in queue; qed");
@@ -271,9 +602,112 @@ impl Queue {
 		if let Some(worker) = worker {
 			assign(self, worker, job);
 		} else {
-			spawn_extra_worker(self, job);
+			let permit = spawn_permit.expect("Permit is acquired if no worker is found; qed");
+			spawn_extra_worker(self, job, spawn_reason, permit);
 		}
 	}
+
+	/// If preemption is enabled and the eldest critical job in the queue has been waiting for
+	/// longer than the preemption threshold, kills a worker executing a job with the normal
+	/// priority. Returns the index of the critical job, which may take the freed up slot.
+	fn preempt_for_starving_critical_job(&mut self) -> Option<usize> {
+		let threshold = self.preemption_threshold?;
+		let job_index = self.queue.iter().position(|job| job.priority.is_critical())?;
+		if self.queue[job_index].waiting_since.elapsed() < threshold {
+			return None
+		}
+
+		let victim = self.workers.running.iter().find_map(|(worker, data)| {
+			if data.job_priority == Some(Priority::Normal) {
+				Some(worker)
+			} else {
+				None
+			}
+		})?;
+		gum::warn!(
+			target: LOG_TARGET,
+			?victim,
+			"preempting a normal priority job to execute a starving critical job",
+		);
+		// Dropping the worker handle kills the worker process
+		if self.workers.running.remove(victim).is_some() {
+			self.metrics.execute_worker().on_retired();
+		}
+		self.preempted.insert(victim);
+
+		Some(job_index)
+	}
+
+	/// Takes a snapshot of the queue state. The snapshot is taken synchronously, so it is
+	/// internally consistent.
+	fn dump_state(&self) -> QueueStateDump {
+		let (mut pending_critical, mut pending_normal) = (Vec::new(), Vec::new());
+		for job in &self.queue {
+			let dump = PendingJobDump {
+				code_hash: job.artifact.id.code_hash,
+				executor_params_hash: job.executor_params.hash(),
+				waiting_ms: job.waiting_since.elapsed().as_millis() as u64,
+			};
+			if job.priority.is_critical() {
+				pending_critical.push(dump);
+			} else {
+				pending_normal.push(dump);
+			}
+		}
+
+		let workers = self
+			.workers
+			.running
+			.iter()
+			.map(|(worker, data)| WorkerDump {
+				worker,
+				pid: data.handle.id(),
+				executor_params_hash: data.executor_params_hash,
+				busy: data.idle.is_none(),
+			})
+			.collect();
+
+		QueueStateDump {
+			pending_critical,
+			pending_normal,
+			workers,
+			spawn_inflight: self.workers.spawn_inflight as u32,
+			paused: self.paused,
+		}
+	}
+
+	/// Returns the time allotted for a worker, spawned for a job with the given priority, to spawn
+	/// and report to the queue.
+	fn spawn_timeout(&self, priority: Priority) -> Duration {
+		if priority.is_critical() {
+			self.critical_spawn_timeout
+		} else {
+			self.spawn_timeout
+		}
+	}
+
+	/// Returns the backoff for the retries of a new worker spawn. Every spawn gets its own
+	/// randomness, so that the workers which fail to spawn at once don't retry at once.
+	fn spawn_backoff(&mut self) -> SpawnBackoff {
+		SpawnBackoff::new(self.spawn_retry_rng.gen())
+	}
+
+	/// Asks the host to remove the artifact, unless the removal of the same artifact has already
+	/// been requested and is not yet acknowledged. In the latter case, the pending acknowledgment
+	/// is shared, so concurrent failures of one artifact result in a single round-trip.
+	fn request_artifact_removal(&mut self, artifact_id: &ArtifactId) -> ArtifactRemoval {
+		if let Some(removal) = self.artifact_removals.get(artifact_id) {
+			return removal.clone()
+		}
+
+		let (reply_to, reply_rx) = oneshot::channel();
+		self.from_queue_tx
+			.unbounded_send(FromQueue::RemoveArtifact { artifact: artifact_id.clone(), reply_to })
+			.expect("from execute queue receiver is listened by the host; qed");
+		let removal = reply_rx.shared();
+		self.artifact_removals.insert(artifact_id.clone(), removal.clone());
+		removal
+	}
 }
 
 async fn purge_dead(metrics: &Metrics, workers: &mut Workers) {
@@ -292,20 +726,59 @@ async fn purge_dead(metrics: &Metrics, workers: &mut Workers) {
 }
 
 fn handle_to_queue(queue: &mut Queue, to_queue: ToQueue) {
-	let ToQueue::Enqueue { artifact, pending_execution_request } = to_queue;
-	let PendingExecutionRequest { exec_timeout, params, executor_params, result_tx } =
+	let (artifact, pending_execution_request) = match to_queue {
+		ToQueue::Enqueue { artifact, pending_execution_request } =>
+			(artifact, pending_execution_request),
+		ToQueue::DumpState { reply_to } => {
+			// The requester may have gone away, that's fine
+			let _ = reply_to.send(queue.dump_state());
+			return
+		},
+		ToQueue::RemoveWorker(worker) => return handle_remove_worker(queue, worker),
+		ToQueue::Pause { drained } => {
+			gum::info!(target: LOG_TARGET, "pausing the execution queue");
+			queue.paused = true;
+			queue.drain_waiters.push(drained);
+			return queue.notify_if_drained()
+		},
+		ToQueue::Resume => {
+			gum::info!(target: LOG_TARGET, "resuming the execution queue");
+			queue.paused = false;
+			// The subscribers of an unfinished drain see their channels canceled
+			queue.drain_waiters.clear();
+			return
+		},
+	};
+	let PendingExecutionRequest { exec_timeout, params, executor_params, priority, result_tx } =
 		pending_execution_request;
+	if queue.paused {
+		gum::debug!(
+			target: LOG_TARGET,
+			validation_code_hash = ?artifact.id.code_hash,
+			"rejecting an artifact execution, the queue is paused",
+		);
+		let _ = result_tx
+			.send(Err(ValidationError::Internal(InternalValidationError::QueuePaused)));
+		return
+	}
 	gum::debug!(
 		target: LOG_TARGET,
 		validation_code_hash = ?artifact.id.code_hash,
 		"enqueueing an artifact for execution",
 	);
 	queue.metrics.execute_enqueued();
+	if let (true, Some(threshold)) = (priority.is_critical(), queue.preemption_threshold) {
+		// Re-check the queue once the job may be starving, even if nothing else happens
+		queue.mux.push(
+			futures_timer::Delay::new(threshold).map(|_| QueueEvent::PreemptionCheck).boxed(),
+		);
+	}
 	let job = ExecuteJob {
 		artifact,
 		exec_timeout,
 		params,
 		executor_params,
+		priority,
 		result_tx,
 		waiting_since: Instant::now(),
 	};
@@ -313,13 +786,56 @@ fn handle_to_queue(queue: &mut Queue, to_queue: ToQueue) {
 	queue.try_assign_next_job(None);
 }
 
+fn handle_remove_worker(queue: &mut Queue, worker: Worker) {
+	let is_idle = if let Some(data) = queue.workers.running.get(worker) {
+		data.idle.is_some()
+	} else {
+		gum::debug!(target: LOG_TARGET, ?worker, "requested to remove an unknown execute worker");
+		return
+	};
+
+	gum::info!(target: LOG_TARGET, ?worker, is_idle, "removing an execute worker on request");
+	queue.metrics.execute_forced_worker_removal();
+	if is_idle {
+		// Dropping the worker handle kills the worker process
+		queue.workers.running.remove(worker);
+		queue.metrics.execute_worker().on_retired();
+		queue.try_assign_next_job(None);
+	} else {
+		queue.retiring.insert(worker);
+	}
+}
+
 async fn handle_mux(queue: &mut Queue, event: QueueEvent) {
 	match event {
-		QueueEvent::Spawn(idle, handle, job) => {
-			handle_worker_spawned(queue, idle, handle, job);
+		QueueEvent::Spawn(idle, handle, job, reason) => {
+			handle_worker_spawned(queue, idle, handle, job, reason);
+		},
+		QueueEvent::SpawnWarm(idle, handle, executor_params) => {
+			handle_warm_worker_spawned(queue, idle, handle, executor_params);
+		},
+		QueueEvent::StartWork(worker, outcome, artifact_id, priority, result_tx) => {
+			handle_job_finish(queue, worker, outcome, artifact_id, priority, result_tx);
+		},
+		QueueEvent::ArtifactRemoved(artifact_id) => {
+			// Several jobs may have been waiting for the same removal. Only forget it once it has
+			// been acknowledged, so that a removal requested later is not dropped by mistake.
+			if queue
+				.artifact_removals
+				.get(&artifact_id)
+				.map_or(false, |removal| removal.peek().is_some())
+			{
+				queue.artifact_removals.remove(&artifact_id);
+			}
+		},
+		QueueEvent::PreemptionCheck => queue.try_assign_next_job(None),
+		QueueEvent::SpawnPermitReleased => {
+			queue.awaiting_spawn_permit = false;
+			queue.try_assign_next_job(None);
 		},
-		QueueEvent::StartWork(worker, outcome, artifact_id, result_tx) => {
-			handle_job_finish(queue, worker, outcome, artifact_id, result_tx).await;
+		QueueEvent::KeepWaitingCheck => {
+			queue.awaiting_keep_waiting_check = false;
+			queue.try_assign_next_job(None);
 		},
 	}
 }
@@ -329,13 +845,15 @@ fn handle_worker_spawned(
 	idle: IdleWorker,
 	handle: WorkerHandle,
 	job: ExecuteJob,
+	reason: WorkerSpawnReason,
 ) {
-	queue.metrics.execute_worker().on_spawned();
+	queue.metrics.execute_worker_spawned(reason);
 	queue.workers.spawn_inflight -= 1;
 	let worker = queue.workers.running.insert(WorkerData {
 		idle: Some(idle),
 		handle,
 		executor_params_hash: job.executor_params.hash(),
+		job_priority: None,
 	});
 
 	gum::debug!(target: LOG_TARGET, ?worker, "execute worker spawned");
@@ -343,13 +861,34 @@ fn handle_worker_spawned(
 	assign(queue, worker, job);
 }
 
+fn handle_warm_worker_spawned(
+	queue: &mut Queue,
+	idle: IdleWorker,
+	handle: WorkerHandle,
+	executor_params: ExecutorParams,
+) {
+	queue.metrics.execute_worker_spawned(WorkerSpawnReason::WarmUp);
+	queue.workers.spawn_inflight -= 1;
+	let worker = queue.workers.running.insert(WorkerData {
+		idle: Some(idle),
+		handle,
+		executor_params_hash: executor_params.hash(),
+		job_priority: None,
+	});
+
+	gum::debug!(target: LOG_TARGET, ?worker, "warm execute worker spawned");
+
+	queue.try_assign_next_job(Some(worker));
+}
+
 /// If there are pending jobs in the queue, schedules the next of them onto the just freed up
 /// worker. Otherwise, puts back into the available workers list.
-async fn handle_job_finish(
+fn handle_job_finish(
 	queue: &mut Queue,
 	worker: Worker,
 	worker_result: Result<WorkerInterfaceResponse, WorkerInterfaceError>,
 	artifact_id: ArtifactId,
+	priority: Priority,
 	result_tx: ResultSender,
 ) {
 	let (idle_worker, result, duration, sync_channel) = match worker_result {
@@ -365,12 +904,7 @@ async fn handle_job_finish(
 		Ok(WorkerInterfaceResponse {
 			worker_response: WorkerResponse { job_response: JobResponse::InvalidCandidate(err), .. },
 			idle_worker,
-		}) => (
-			Some(idle_worker),
-			Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err))),
-			None,
-			None,
-		),
+		}) => (Some(idle_worker), Err(ExecuteJobFailure::InvalidCandidate(err)), None, None),
 		Ok(WorkerInterfaceResponse {
 			worker_response:
 				WorkerResponse { job_response: JobResponse::RuntimeConstruction(err), .. },
@@ -378,54 +912,42 @@ async fn handle_job_finish(
 		}) => {
 			// The task for artifact removal is executed concurrently with
 			// the message to the host on the execution result.
-			let (result_tx, result_rx) = oneshot::channel();
-			queue
-				.from_queue_tx
-				.unbounded_send(FromQueue::RemoveArtifact {
-					artifact: artifact_id.clone(),
-					reply_to: result_tx,
-				})
-				.expect("from execute queue receiver is listened by the host; qed");
+			let removal = queue.request_artifact_removal(&artifact_id);
 			(
 				Some(idle_worker),
-				Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::RuntimeConstruction(
-					err,
-				))),
+				Err(ExecuteJobFailure::RuntimeConstruction(err)),
 				None,
-				Some(result_rx),
+				Some(removal),
 			)
 		},
 
-		Err(WorkerInterfaceError::InternalError(err)) |
-		Err(WorkerInterfaceError::WorkerError(WorkerError::InternalError(err))) =>
-			(None, Err(ValidationError::Internal(err)), None, None),
-		// Either the worker or the job timed out. Kill the worker in either case. Treated as
-		// definitely-invalid, because if we timed out, there's no time left for a retry.
-		Err(WorkerInterfaceError::HardTimeout) |
-		Err(WorkerInterfaceError::WorkerError(WorkerError::JobTimedOut)) =>
-			(None, Err(ValidationError::Invalid(InvalidCandidate::HardTimeout)), None, None),
-		// "Maybe invalid" errors (will retry).
-		Err(WorkerInterfaceError::CommunicationErr(_err)) => (
-			None,
-			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousWorkerDeath)),
-			None,
-			None,
-		),
-		Err(WorkerInterfaceError::WorkerError(WorkerError::JobDied { err, .. })) => (
-			None,
-			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousJobDeath(err))),
-			None,
-			None,
-		),
-		Err(WorkerInterfaceError::WorkerError(WorkerError::JobError(err))) => (
-			None,
-			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::JobError(err.to_string()))),
-			None,
-			None,
-		),
+		// The worker is killed in case of any error.
+		Err(WorkerInterfaceError::InternalError(err)) =>
+			(None, Err(ExecuteJobFailure::InternalError(err)), None, None),
+		Err(WorkerInterfaceError::HardTimeout) =>
+			(None, Err(ExecuteJobFailure::HardTimeout), None, None),
+		Err(WorkerInterfaceError::CommunicationErr(err)) =>
+			(None, Err(ExecuteJobFailure::CommunicationErr(err)), None, None),
+		Err(WorkerInterfaceError::WorkerError(err)) =>
+			(None, Err(ExecuteJobFailure::WorkerError(err)), None, None),
+	};
+	let result = result.map_err(|failure| queue.error_classifier.classify(failure));
+	// The job of a preempted worker is never reported as invalid, whatever the outcome
+	let result = if queue.preempted.remove(&worker) {
+		Err(ValidationError::Internal(InternalValidationError::Preempted))
+	} else {
+		result
 	};
 
 	queue.metrics.execute_finished();
+	let outcome = match result {
+		Ok(_) => ExecuteOutcome::Ok,
+		Err(ValidationError::Invalid(_)) => ExecuteOutcome::Invalid,
+		Err(ValidationError::PossiblyInvalid(_)) => ExecuteOutcome::PossiblyInvalid,
+		Err(ValidationError::Preparation(_) | ValidationError::Internal(_)) =>
+			ExecuteOutcome::Internal,
+	};
+	queue.metrics.execute_concluded(priority, outcome);
 	if let Err(ref err) = result {
 		gum::warn!(
 			target: LOG_TARGET,
@@ -446,15 +968,24 @@ async fn handle_job_finish(
 		);
 	}
 
-	if let Some(sync_channel) = sync_channel {
-		// err means the sender is dropped (the artifact is already removed from the cache)
-		// so that's legitimate to ignore the result
-		let _ = sync_channel.await;
-	}
-
 	// First we send the result. It may fail due to the other end of the channel being dropped,
 	// that's legitimate and we don't treat that as an error.
-	let _ = result_tx.send(result);
+	if let Some(sync_channel) = sync_channel {
+		// The result is sent only after the host has removed the artifact, but the queue doesn't
+		// wait for it and keeps serving the other jobs meanwhile.
+		queue.mux.push(
+			async move {
+				// err means the sender is dropped (the artifact is already removed from the cache)
+				// so that's legitimate to ignore the result
+				let _ = sync_channel.await;
+				let _ = result_tx.send(result);
+				QueueEvent::ArtifactRemoved(artifact_id)
+			}
+			.boxed(),
+		);
+	} else {
+		let _ = result_tx.send(result);
+	}
 
 	// Then, we should deal with the worker:
 	//
@@ -463,9 +994,14 @@ async fn handle_job_finish(
 	//
 	// - if the `idle_worker` token was consumed, all the metadata pertaining to that worker should
 	//   be removed.
+	//
+	// - if the worker was requested to be removed, it is not reused.
+	let is_retiring = queue.retiring.remove(&worker);
+	let idle_worker = idle_worker.filter(|_| !is_retiring);
 	if let Some(idle_worker) = idle_worker {
 		if let Some(data) = queue.workers.running.get_mut(worker) {
 			data.idle = Some(idle_worker);
+			data.job_priority = None;
 			return queue.try_assign_next_job(Some(worker))
 		}
 	} else {
@@ -478,27 +1014,68 @@ async fn handle_job_finish(
 	queue.try_assign_next_job(None);
 }
 
-fn spawn_extra_worker(queue: &mut Queue, job: ExecuteJob) {
-	queue.metrics.execute_worker().on_begin_spawn();
-	gum::debug!(target: LOG_TARGET, "spawning an extra worker");
+fn spawn_extra_worker(
+	queue: &mut Queue,
+	job: ExecuteJob,
+	reason: WorkerSpawnReason,
+	permit: SpawnPermit,
+) {
+	queue.metrics.execute_worker_begin_spawn(reason);
+	gum::debug!(target: LOG_TARGET, ?reason, priority = ?job.priority, "spawning an extra worker");
 
+	let spawn_timeout = queue.spawn_timeout(job.priority);
 	queue.mux.push(
 		spawn_worker_task(
 			queue.program_path.clone(),
 			queue.cache_path.clone(),
 			job,
-			queue.spawn_timeout,
+			reason,
+			spawn_timeout,
+			queue.spawn_backoff(),
 			queue.node_version.clone(),
 			queue.security_status.clone(),
+			permit,
 		)
 		.boxed(),
 	);
 	queue.workers.spawn_inflight += 1;
 }
 
+/// Spawns an idle worker, which is kept warm to execute the jobs with the given executor
+/// parameters without paying the spawn latency.
+fn spawn_warm_worker(queue: &mut Queue, executor_params: ExecutorParams, permit: SpawnPermit) {
+	queue.metrics.execute_worker_begin_spawn(WorkerSpawnReason::WarmUp);
+	gum::debug!(target: LOG_TARGET, "spawning a warm worker");
+
+	let program_path = queue.program_path.clone();
+	let cache_path = queue.cache_path.clone();
+	let spawn_timeout = queue.spawn_timeout;
+	let backoff = queue.spawn_backoff();
+	let node_version = queue.node_version.clone();
+	let security_status = queue.security_status.clone();
+	queue.mux.push(
+		async move {
+			let (idle, handle) = spawn_worker_with_retries(
+				&program_path,
+				&cache_path,
+				executor_params.clone(),
+				spawn_timeout,
+				backoff,
+				node_version.as_deref(),
+				security_status,
+			)
+			.await;
+			drop(permit);
+			QueueEvent::SpawnWarm(idle, handle, executor_params)
+		}
+		.boxed(),
+	);
+	queue.workers.spawn_inflight += 1;
+}
+
 /// Spawns a new worker to execute a pre-assigned job.
-/// A worker is never spawned as idle; a job to be executed by the worker has to be determined
-/// beforehand. In such a way, a race condition is avoided: during the worker being spawned,
+/// Apart from the warm workers, a worker is never spawned as idle; a job to be executed by the
+/// worker has to be determined beforehand. In such a way, a race condition is avoided: during the worker being spawned,
 /// another job in the queue, with an incompatible execution environment, may become stale, and
 /// the queue would have to kill a newly started worker and spawn another one.
 /// Nevertheless, if the worker finishes executing the job, it becomes idle and may be used to
@@ -507,29 +1084,86 @@ async fn spawn_worker_task(
 	program_path: PathBuf,
 	cache_path: PathBuf,
 	job: ExecuteJob,
+	reason: WorkerSpawnReason,
 	spawn_timeout: Duration,
+	backoff: SpawnBackoff,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
+	permit: SpawnPermit,
 ) -> QueueEvent {
+	let (idle, handle) = spawn_worker_with_retries(
+		&program_path,
+		&cache_path,
+		job.executor_params.clone(),
+		spawn_timeout,
+		backoff,
+		node_version.as_deref(),
+		security_status,
+	)
+	.await;
+	// The worker is spawned, let the other spawns proceed
+	drop(permit);
+	QueueEvent::Spawn(idle, handle, job, reason)
+}
+
+/// The delay before the first retry of a failed worker spawn.
+const SPAWN_RETRY_BASE_DELAY: Duration = Duration::from_secs(3);
+/// The maximal delay between the retries of a failed worker spawn.
+const SPAWN_RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Exponential backoff between the retries of a failed worker spawn.
+///
+/// The delay doubles with every failed attempt up to `SPAWN_RETRY_MAX_DELAY` and is randomly
+/// shortened by up to a half.
+struct SpawnBackoff {
+	rng: StdRng,
+	failed_attempts: u32,
+}
+
+impl SpawnBackoff {
+	fn new(seed: u64) -> Self {
+		Self { rng: StdRng::seed_from_u64(seed), failed_attempts: 0 }
+	}
+
+	/// Returns the delay before the next attempt to spawn a worker.
+	fn next_delay(&mut self) -> Duration {
+		let delay = SPAWN_RETRY_BASE_DELAY
+			.saturating_mul(2u32.saturating_pow(self.failed_attempts))
+			.min(SPAWN_RETRY_MAX_DELAY);
+		self.failed_attempts = self.failed_attempts.saturating_add(1);
+		delay.mul_f64(self.rng.gen_range(0.5..=1.0))
+	}
+}
+
+/// Spawns a new worker, retrying with the given backoff until it succeeds.
+async fn spawn_worker_with_retries(
+	program_path: &Path,
+	cache_path: &Path,
+	executor_params: ExecutorParams,
+	spawn_timeout: Duration,
+	mut backoff: SpawnBackoff,
+	node_version: Option<&str>,
+	security_status: SecurityStatus,
+) -> (IdleWorker, WorkerHandle) {
 	use futures_timer::Delay;
 
 	loop {
 		match super::worker_interface::spawn(
-			&program_path,
-			&cache_path,
-			job.executor_params.clone(),
+			program_path,
+			cache_path,
+			executor_params.clone(),
 			spawn_timeout,
-			node_version.as_deref(),
+			node_version,
 			security_status.clone(),
 		)
 		.await
 		{
-			Ok((idle, handle)) => break QueueEvent::Spawn(idle, handle, job),
+			Ok(spawned) => break spawned,
 			Err(err) => {
 				gum::warn!(target: LOG_TARGET, "failed to spawn an execute worker: {:?}", err);
 
 				// Assume that the failure is intermittent and retry after a delay.
-				Delay::new(Duration::from_secs(3)).await;
+				Delay::new(backoff.next_delay()).await;
 			},
 		}
 	}
@@ -557,6 +1191,11 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 		job.executor_params.hash()
 	);
 
+	queue.last_executor_params = Some(job.executor_params.clone());
+	if let Some(data) = queue.workers.running.get_mut(worker) {
+		data.job_priority = Some(job.priority);
+	}
+
 	let idle = queue.workers.claim_idle(worker).expect(
 		"this caller must supply a worker which is idle and running;
 			thus claim_idle cannot return None;
@@ -576,20 +1215,64 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 				job.params,
 			)
 			.await;
-			QueueEvent::StartWork(worker, result, job.artifact.id, job.result_tx)
+			QueueEvent::StartWork(worker, result, job.artifact.id, job.priority, job.result_tx)
 		}
 		.boxed(),
 	);
 }
 
+/// Returns the number of threads an execution job with the given executor parameters is expected
+/// to keep busy.
+///
+/// Every job runs on a single execution thread. A job whose native stack limit is raised above the
+/// default is counted as a proportionally larger number of threads, since its execution thread
+/// may take that much more of the host resources.
+pub fn threads_per_job_hint(executor_params: &ExecutorParams) -> usize {
+	let native_stack_max = executor_params
+		.iter()
+		.find_map(|param| match param {
+			ExecutorParam::StackNativeMax(native_stack_max) => Some(*native_stack_max),
+			_ => None,
+		})
+		.unwrap_or(DEFAULT_NATIVE_STACK_MAX);
+	native_stack_max.div_ceil(DEFAULT_NATIVE_STACK_MAX).max(1) as usize
+}
+
+/// Returns the maximum number of execute workers. Unless the value is given explicitly, it is
+/// derived from the number of CPU cores, so that the jobs running at the same time don't keep more
+/// threads busy than there are cores. At least one worker is always allowed.
+pub fn derive_worker_capacity(
+	explicit: Option<usize>,
+	num_cpus: usize,
+	threads_per_job: usize,
+) -> usize {
+	let capacity = explicit.unwrap_or_else(|| (num_cpus / threads_per_job.max(1)).max(1));
+	gum::info!(
+		target: LOG_TARGET,
+		?explicit,
+		num_cpus,
+		threads_per_job,
+		capacity,
+		"chose the maximum number of execute workers",
+	);
+	capacity
+}
+
 pub fn start(
 	metrics: Metrics,
 	program_path: PathBuf,
 	cache_path: PathBuf,
 	worker_capacity: usize,
+	min_warm_workers: usize,
+	warm_workers_target: WarmWorkersTarget,
+	spawn_limiter: SpawnLimiter,
 	spawn_timeout: Duration,
+	critical_spawn_timeout: Duration,
+	spawn_retry_seed: Option<u64>,
+	preemption_threshold: Option<Duration>,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
+	error_classifier: Arc<dyn ExecuteErrorClassifier>,
 ) -> (mpsc::Sender<ToQueue>, mpsc::UnboundedReceiver<FromQueue>, impl Future<Output = ()>) {
 	let (to_queue_tx, to_queue_rx) = mpsc::channel(20);
 	let (from_queue_tx, from_queue_rx) = mpsc::unbounded();
@@ -599,12 +1282,851 @@ pub fn start(
 		program_path,
 		cache_path,
 		worker_capacity,
+		min_warm_workers,
+		warm_workers_target,
+		spawn_limiter,
 		spawn_timeout,
+		critical_spawn_timeout,
+		spawn_retry_seed,
+		preemption_threshold,
 		node_version,
 		security_status,
+		error_classifier,
 		to_queue_rx,
 		from_queue_tx,
 	)
 	.run();
 	(to_queue_tx, from_queue_rx, run)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::worker_interface::spawn_dummy_worker;
+	use assert_matches::assert_matches;
+	use polkadot_node_core_pvf_common::pvf::PvfPrepData;
+	use polkadot_node_metrics::metrics::{prometheus, Metrics as _};
+	use polkadot_parachain_primitives::primitives::ValidationResult;
+	use polkadot_primitives::ExecutorParam;
+	use std::path::Path;
+
+	fn artifact_id(discriminator: u32) -> ArtifactId {
+		ArtifactId::from_pvf_prep_data(&PvfPrepData::from_discriminator(discriminator))
+	}
+
+	/// Executor parameters which can be uniquely identified by the given number.
+	fn executor_params(discriminator: u32) -> ExecutorParams {
+		ExecutorParams::from(&[ExecutorParam::MaxMemoryPages(discriminator + 1)][..])
+	}
+
+	fn enqueue(queue: &mut Queue, executor_params: ExecutorParams) {
+		enqueue_with_priority(queue, executor_params, Priority::Normal)
+	}
+
+	fn enqueue_with_priority(
+		queue: &mut Queue,
+		executor_params: ExecutorParams,
+		priority: Priority,
+	) {
+		let _result_rx = enqueue_and_watch(queue, executor_params, priority);
+	}
+
+	/// Enqueues a job, returning the receiver of its result.
+	fn enqueue_and_watch(
+		queue: &mut Queue,
+		executor_params: ExecutorParams,
+		priority: Priority,
+	) -> oneshot::Receiver<Result<ValidationResult, ValidationError>> {
+		let (result_tx, result_rx) = oneshot::channel();
+		handle_to_queue(
+			queue,
+			ToQueue::Enqueue {
+				artifact: ArtifactPathId::new(artifact_id(1), Path::new("artifact")),
+				pending_execution_request: PendingExecutionRequest {
+					exec_timeout: Duration::from_secs(10),
+					params: vec![],
+					executor_params,
+					priority,
+					result_tx,
+				},
+			},
+		);
+		result_rx
+	}
+
+	/// Adds an idle worker compatible with the given executor parameters to the queue.
+	async fn add_idle_worker(queue: &mut Queue, executor_params: &ExecutorParams) -> Worker {
+		let (idle, handle) = spawn_dummy_worker(&queue.cache_path).await;
+		queue.workers.running.insert(WorkerData {
+			idle: Some(idle),
+			handle,
+			executor_params_hash: executor_params.hash(),
+			job_priority: None,
+		})
+	}
+
+	fn counter_value(registry: &prometheus::Registry, name: &str, label_value: &str) -> u64 {
+		registry
+			.gather()
+			.iter()
+			.filter(|family| family.get_name() == name)
+			.flat_map(|family| family.get_metric())
+			.filter(|metric| metric.get_label().iter().any(|label| label.get_value() == label_value))
+			.map(|metric| metric.get_counter().get_value() as u64)
+			.sum()
+	}
+
+	fn forced_worker_removals(registry: &prometheus::Registry) -> u64 {
+		registry
+			.gather()
+			.iter()
+			.filter(|family| family.get_name() == "polkadot_pvf_execute_forced_worker_removal")
+			.flat_map(|family| family.get_metric())
+			.map(|metric| metric.get_counter().get_value() as u64)
+			.sum()
+	}
+
+	struct TestQueue {
+		queue: Queue,
+		_from_queue_rx: mpsc::UnboundedReceiver<FromQueue>,
+		registry: prometheus::Registry,
+		_cache_dir: tempfile::TempDir,
+	}
+
+	fn test_queue_with_metrics(worker_capacity: usize) -> TestQueue {
+		let registry = prometheus::Registry::new();
+		let metrics = Metrics::try_register(&registry).unwrap();
+		let cache_dir = tempfile::tempdir().unwrap();
+		let (_to_queue_tx, to_queue_rx) = mpsc::channel(20);
+		let (from_queue_tx, from_queue_rx) = mpsc::unbounded();
+		let queue = Queue::new(
+			metrics,
+			PathBuf::new(),
+			cache_dir.path().to_owned(),
+			worker_capacity,
+			0,
+			WarmWorkersTarget::MostRecentlyUsed,
+			SpawnLimiter::unlimited(),
+			Duration::from_secs(3),
+			Duration::from_secs(3),
+			Some(0),
+			None,
+			None,
+			SecurityStatus::default(),
+			Arc::new(DefaultExecuteErrorClassifier),
+			to_queue_rx,
+			from_queue_tx,
+		);
+		TestQueue { queue, _from_queue_rx: from_queue_rx, registry, _cache_dir: cache_dir }
+	}
+
+	fn test_queue(worker_capacity: usize) -> (Queue, mpsc::UnboundedReceiver<FromQueue>) {
+		let (_to_queue_tx, to_queue_rx) = mpsc::channel(20);
+		let (from_queue_tx, from_queue_rx) = mpsc::unbounded();
+		let queue = Queue::new(
+			Metrics::default(),
+			PathBuf::new(),
+			PathBuf::new(),
+			worker_capacity,
+			0,
+			WarmWorkersTarget::MostRecentlyUsed,
+			SpawnLimiter::unlimited(),
+			Duration::from_secs(3),
+			Duration::from_secs(3),
+			Some(0),
+			None,
+			None,
+			SecurityStatus::default(),
+			Arc::new(DefaultExecuteErrorClassifier),
+			to_queue_rx,
+			from_queue_tx,
+		);
+		(queue, from_queue_rx)
+	}
+
+	#[test]
+	fn worker_capacity_is_derived_from_cpu_count_and_thread_hint() {
+		assert_eq!(threads_per_job_hint(&executor_params(1)), 1);
+		let large_stack = |native_stack_max| {
+			ExecutorParams::from(&[ExecutorParam::StackNativeMax(native_stack_max)][..])
+		};
+		assert_eq!(threads_per_job_hint(&large_stack(DEFAULT_NATIVE_STACK_MAX / 2)), 1);
+		assert_eq!(threads_per_job_hint(&large_stack(DEFAULT_NATIVE_STACK_MAX + 1)), 2);
+		assert_eq!(threads_per_job_hint(&large_stack(4 * DEFAULT_NATIVE_STACK_MAX)), 4);
+		assert_eq!(derive_worker_capacity(None, 8, 1), 8);
+		assert_eq!(derive_worker_capacity(None, 8, 3), 2);
+		// At least one worker is allowed, however few cores there are.
+		assert_eq!(derive_worker_capacity(None, 2, 4), 1);
+		assert_eq!(derive_worker_capacity(None, 8, 0), 8);
+		// The explicit value overrides the derived one.
+		assert_eq!(derive_worker_capacity(Some(2), 8, 1), 2);
+		assert_eq!(derive_worker_capacity(Some(16), 8, 1), 16);
+	}
+
+	#[tokio::test]
+	async fn concurrent_failures_share_single_artifact_removal() {
+		let (mut queue, mut from_queue_rx) = test_queue(2);
+		let cache_dir = tempfile::tempdir().unwrap();
+		let fail_job = |queue: &mut Queue, idle_worker| {
+			let response = WorkerInterfaceResponse {
+				worker_response: WorkerResponse {
+					job_response: JobResponse::RuntimeConstruction("corrupted".to_string()),
+					duration: Duration::from_secs(1),
+				},
+				idle_worker,
+			};
+			let (result_tx, result_rx) = oneshot::channel();
+			handle_job_finish(
+				queue,
+				Worker::default(),
+				Ok(response),
+				artifact_id(1),
+				Priority::Normal,
+				result_tx,
+			);
+			result_rx
+		};
+
+		// Two jobs for the same artifact fail with a runtime construction error at once.
+		let (idle, _first_handle) = spawn_dummy_worker(cache_dir.path()).await;
+		let mut first = fail_job(&mut queue, idle);
+		let (idle, _second_handle) = spawn_dummy_worker(cache_dir.path()).await;
+		let mut second = fail_job(&mut queue, idle);
+
+		// Only one removal is requested from the host.
+		let reply_to = assert_matches!(
+			from_queue_rx.try_next(),
+			Ok(Some(FromQueue::RemoveArtifact { artifact, reply_to })) => {
+				assert_eq!(artifact, artifact_id(1));
+				reply_to
+			}
+		);
+		assert!(from_queue_rx.try_next().is_err());
+
+		// The results are held back until the host acknowledges the removal.
+		assert_matches!(first.try_recv(), Ok(None));
+		assert_matches!(second.try_recv(), Ok(None));
+
+		// Both jobs are released by a single acknowledgment.
+		reply_to.send(()).unwrap();
+		for _ in 0..2 {
+			let event = queue.mux.next().await.unwrap();
+			handle_mux(&mut queue, event).await;
+		}
+		for result in [first, second] {
+			assert_matches!(
+				result.await,
+				Ok(Err(ValidationError::PossiblyInvalid(
+					PossiblyInvalidError::RuntimeConstruction(_)
+				)))
+			);
+		}
+
+		// Once the removal is acknowledged, the next failure requests it again.
+		let (idle, _third_handle) = spawn_dummy_worker(cache_dir.path()).await;
+		let _third = fail_job(&mut queue, idle);
+		assert_matches!(from_queue_rx.try_next(), Ok(Some(FromQueue::RemoveArtifact { .. })));
+	}
+
+	#[tokio::test]
+	async fn removals_of_different_artifacts_are_not_shared() {
+		let (mut queue, mut from_queue_rx) = test_queue(2);
+
+		let _first = queue.request_artifact_removal(&artifact_id(1));
+		let _second = queue.request_artifact_removal(&artifact_id(2));
+
+		assert_matches!(from_queue_rx.try_next(), Ok(Some(FromQueue::RemoveArtifact { .. })));
+		assert_matches!(from_queue_rx.try_next(), Ok(Some(FromQueue::RemoveArtifact { .. })));
+		assert!(from_queue_rx.try_next().is_err());
+	}
+
+	#[tokio::test]
+	async fn spawn_for_capacity_growth_is_attributed() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(2);
+
+		enqueue(&mut queue, executor_params(1));
+
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "capacity-growth"), 1);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 0);
+	}
+
+	#[tokio::test]
+	async fn spawn_after_killing_idle_worker_is_attributed() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(1);
+		add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The only worker slot is taken by an idle worker with other executor parameters, so the
+		// queue has to kill it to execute the job, once the job has waited for too long.
+		enqueue(&mut queue, executor_params(2));
+		keep_waiting_elapsed(&mut queue);
+
+		assert!(queue.workers.running.is_empty());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "capacity-growth"), 0);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 1);
+
+		// The spawned worker is attributed to the same reason.
+		let (idle, handle) = spawn_dummy_worker(&queue.cache_path).await;
+		let job = ExecuteJob {
+			artifact: ArtifactPathId::new(artifact_id(1), Path::new("artifact")),
+			exec_timeout: Duration::from_secs(10),
+			params: vec![],
+			executor_params: executor_params(2),
+			priority: Priority::Normal,
+			result_tx: oneshot::channel().0,
+			waiting_since: Instant::now(),
+		};
+		handle_worker_spawned(&mut queue, idle, handle, job, WorkerSpawnReason::RespawnAfterKill);
+		let spawned = "polkadot_pvf_execute_worker_spawned_by_reason";
+		assert_eq!(counter_value(&registry, spawned, "capacity-growth"), 0);
+		assert_eq!(counter_value(&registry, spawned, "respawn-after-kill"), 1);
+	}
+
+	/// Makes the eldest job in the queue wait for longer than [`MAX_KEEP_WAITING`] and re-checks
+	/// the queue, as if the timer scheduled for the job has fired.
+	fn keep_waiting_elapsed(queue: &mut Queue) {
+		queue.queue[0].waiting_since = Instant::now() - MAX_KEEP_WAITING;
+		queue.try_assign_next_job(None);
+	}
+
+	#[tokio::test]
+	async fn last_idle_worker_is_kept_if_extra_worker_is_affordable() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(2);
+		let idle = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The job with other executor parameters is executed on an extra worker.
+		enqueue(&mut queue, executor_params(2));
+		assert!(queue.queue.is_empty());
+		assert!(queue.workers.running.get(idle).unwrap().idle.is_some());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "capacity-growth"), 1);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 0);
+	}
+
+	#[tokio::test]
+	async fn last_idle_worker_is_killed_once_job_waited_too_long() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(1);
+		let idle = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// No extra worker can be afforded, so the job waits, and the idle worker is kept.
+		enqueue(&mut queue, executor_params(2));
+		assert_eq!(queue.queue.len(), 1);
+		assert!(queue.workers.running.contains_key(idle));
+		assert_eq!(queue.workers.spawn_inflight, 0);
+
+		// Once the job has waited for too long, the idle worker is killed to execute it.
+		keep_waiting_elapsed(&mut queue);
+		assert!(queue.queue.is_empty());
+		assert!(queue.workers.running.is_empty());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "capacity-growth"), 0);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 1);
+	}
+
+	#[tokio::test]
+	async fn single_keep_waiting_check_is_scheduled() {
+		let TestQueue { mut queue, .. } = test_queue_with_metrics(1);
+		add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The waiting job is re-checked several times before it has waited for too long.
+		enqueue(&mut queue, executor_params(2));
+		enqueue(&mut queue, executor_params(2));
+		queue.try_assign_next_job(None);
+		assert_eq!(queue.queue.len(), 2);
+		assert!(queue.awaiting_keep_waiting_check);
+		assert_eq!(queue.mux.len(), 1);
+
+		// Once the check fires and the job still has to wait, the next one is scheduled.
+		drop(std::mem::take(&mut queue.mux));
+		handle_mux(&mut queue, QueueEvent::KeepWaitingCheck).await;
+		assert_eq!(queue.queue.len(), 2);
+		assert!(queue.awaiting_keep_waiting_check);
+		assert_eq!(queue.mux.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn queue_maintains_min_warm_workers_after_jobs_complete() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(3);
+		queue.workers.min_warm = 2;
+		let worker = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The job is assigned to the idle worker, which dies executing it.
+		enqueue(&mut queue, executor_params(1));
+		assert!(queue.workers.running.get(worker).unwrap().idle.is_none());
+		let (result_tx, _result_rx) = oneshot::channel();
+		handle_job_finish(
+			&mut queue,
+			worker,
+			Err(job_died()),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+		assert!(queue.workers.running.is_empty());
+
+		// The queue spawns warm workers with the most recently used executor parameters.
+		queue.maintain_warm_workers();
+		assert_eq!(queue.workers.spawn_inflight, 2);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "warm-up"), 2);
+
+		for _ in 0..2 {
+			let (idle, handle) = spawn_dummy_worker(&queue.cache_path).await;
+			handle_warm_worker_spawned(&mut queue, idle, handle, executor_params(1));
+		}
+		assert_eq!(queue.workers.spawn_inflight, 0);
+		assert_eq!(queue.workers.running.len(), 2);
+		assert!(queue.workers.find_available(executor_params(1).hash()).is_some());
+
+		// Once the minimum is reached, no more warm workers are spawned.
+		queue.maintain_warm_workers();
+		assert_eq!(queue.workers.spawn_inflight, 0);
+		assert_eq!(counter_value(&registry, spawning, "warm-up"), 2);
+	}
+
+	#[tokio::test]
+	async fn warm_workers_are_spawned_with_upcoming_executor_params() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(3);
+		queue.workers.min_warm = 2;
+		queue.warm_workers_target = WarmWorkersTarget::Upcoming(executor_params(2));
+
+		// The warm workers don't wait for a job to learn the executor parameters.
+		assert_eq!(queue.warm_executor_params(), Some(executor_params(2)));
+		queue.maintain_warm_workers();
+		assert_eq!(queue.workers.spawn_inflight, 2);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "warm-up"), 2);
+
+		// The most recently used executor parameters don't affect the warm workers.
+		queue.last_executor_params = Some(executor_params(1));
+		assert_eq!(queue.warm_executor_params(), Some(executor_params(2)));
+
+		queue.warm_workers_target = WarmWorkersTarget::MostRecentlyUsed;
+		assert_eq!(queue.warm_executor_params(), Some(executor_params(1)));
+	}
+
+	#[tokio::test]
+	async fn warm_workers_are_not_retired_below_minimum() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(2);
+		queue.workers.min_warm = 1;
+		let warm = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The job with other executor parameters is executed on an extra worker, while the warm
+		// one is kept.
+		enqueue(&mut queue, executor_params(2));
+		assert!(queue.workers.running.get(warm).unwrap().idle.is_some());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		let spawning = "polkadot_pvf_execute_worker_spawning_by_reason";
+		assert_eq!(counter_value(&registry, spawning, "capacity-growth"), 1);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 0);
+
+		// When no extra worker can be afforded, the warm worker is killed as usual.
+		enqueue(&mut queue, executor_params(3));
+		keep_waiting_elapsed(&mut queue);
+		assert!(queue.workers.running.is_empty());
+		assert_eq!(queue.workers.spawn_inflight, 2);
+		assert_eq!(counter_value(&registry, spawning, "respawn-after-kill"), 1);
+	}
+
+	#[tokio::test]
+	async fn spawn_is_deferred_until_shared_limit_allows() {
+		let (mut queue, _from_queue_rx) = test_queue(2);
+		queue.spawn_limiter = SpawnLimiter::new(1);
+		let idle = add_idle_worker(&mut queue, &executor_params(1)).await;
+		// The only permit is taken by the prepare queue.
+		let prepare_spawn = queue.spawn_limiter.try_acquire().unwrap();
+
+		// The job waits in the queue, and the idle worker is not killed to make room for it.
+		enqueue(&mut queue, executor_params(2));
+		assert_eq!(queue.queue.len(), 1);
+		assert_eq!(queue.workers.spawn_inflight, 0);
+		assert!(queue.workers.running.contains_key(idle));
+		assert!(queue.awaiting_spawn_permit);
+
+		// Once the permit is released, the deferred spawn proceeds.
+		drop(prepare_spawn);
+		let event = queue.mux.select_next_some().await;
+		assert!(matches!(event, QueueEvent::SpawnPermitReleased));
+		handle_mux(&mut queue, event).await;
+		assert!(queue.queue.is_empty());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+		assert!(!queue.awaiting_spawn_permit);
+		assert!(queue.spawn_limiter.try_acquire().is_none());
+	}
+
+	#[test]
+	fn critical_jobs_use_critical_spawn_timeout() {
+		let (mut queue, _from_queue_rx) = test_queue(1);
+		queue.critical_spawn_timeout = Duration::from_secs(1);
+
+		assert_eq!(queue.spawn_timeout(Priority::Critical), Duration::from_secs(1));
+		assert_eq!(queue.spawn_timeout(Priority::Normal), Duration::from_secs(3));
+	}
+
+	/// Occupies the only worker slot with a job with the normal priority and then enqueues a
+	/// critical job.
+	async fn starve_critical_job(queue: &mut Queue) -> Worker {
+		let worker = add_idle_worker(queue, &executor_params(1)).await;
+		enqueue(queue, executor_params(1));
+		assert_eq!(queue.workers.running.get(worker).unwrap().job_priority, Some(Priority::Normal));
+
+		enqueue_with_priority(queue, executor_params(1), Priority::Critical);
+		worker
+	}
+
+	#[tokio::test]
+	async fn starving_critical_job_preempts_normal_job_when_enabled() {
+		let (mut queue, _from_queue_rx) = test_queue(1);
+		queue.preemption_threshold = Some(Duration::ZERO);
+		let worker = starve_critical_job(&mut queue).await;
+
+		// The normal job is killed and a worker is spawned for the critical one.
+		assert!(queue.workers.running.is_empty());
+		assert!(queue.queue.is_empty());
+		assert_eq!(queue.workers.spawn_inflight, 1);
+
+		// The killed job is reported as preempted rather than invalid.
+		let (result_tx, mut result_rx) = oneshot::channel();
+		let error = WorkerInterfaceError::CommunicationErr(io::ErrorKind::BrokenPipe.into());
+		handle_job_finish(
+			&mut queue,
+			worker,
+			Err(error),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+		assert_matches!(
+			result_rx.try_recv(),
+			Ok(Some(Err(ValidationError::Internal(InternalValidationError::Preempted))))
+		);
+		assert!(queue.preempted.is_empty());
+	}
+
+	#[tokio::test]
+	async fn starving_critical_job_waits_when_preemption_disabled() {
+		let (mut queue, _from_queue_rx) = test_queue(1);
+		let worker = starve_critical_job(&mut queue).await;
+
+		// The critical job waits for the running job to conclude.
+		assert_eq!(queue.workers.running.get(worker).unwrap().job_priority, Some(Priority::Normal));
+		assert_eq!(queue.queue.len(), 1);
+		assert_eq!(queue.workers.spawn_inflight, 0);
+		assert!(queue.preempted.is_empty());
+	}
+
+	fn pending_job(executor_params: ExecutorParams, priority: Priority) -> ExecuteJob {
+		ExecuteJob {
+			artifact: ArtifactPathId::new(artifact_id(1), Path::new("artifact")),
+			exec_timeout: Duration::from_secs(10),
+			params: vec![],
+			executor_params,
+			priority,
+			result_tx: oneshot::channel().0,
+			waiting_since: Instant::now(),
+		}
+	}
+
+	#[tokio::test]
+	async fn state_dump_reflects_queue_state() {
+		let (mut queue, _from_queue_rx) = test_queue(4);
+		let busy = add_idle_worker(&mut queue, &executor_params(1)).await;
+		let idle = add_idle_worker(&mut queue, &executor_params(2)).await;
+		enqueue(&mut queue, executor_params(1));
+		queue.queue.push_back(pending_job(executor_params(3), Priority::Normal));
+		queue.queue.push_back(pending_job(executor_params(4), Priority::Critical));
+		queue.queue.push_back(pending_job(executor_params(5), Priority::Normal));
+		queue.workers.spawn_inflight = 1;
+
+		let (reply_to, mut reply_rx) = oneshot::channel();
+		handle_to_queue(&mut queue, ToQueue::DumpState { reply_to });
+		let dump = reply_rx.try_recv().unwrap().unwrap();
+
+		let pending_hashes = |jobs: &[PendingJobDump]| {
+			jobs.iter().map(|job| job.executor_params_hash).collect::<Vec<_>>()
+		};
+		assert_eq!(pending_hashes(&dump.pending_critical), vec![executor_params(4).hash()]);
+		assert_eq!(
+			pending_hashes(&dump.pending_normal),
+			vec![executor_params(3).hash(), executor_params(5).hash()],
+		);
+		assert!(dump.pending_normal.iter().all(|job| job.code_hash == artifact_id(1).code_hash));
+		assert_eq!(dump.spawn_inflight, 1);
+
+		let worker_dump = |worker: Worker, executor_params: ExecutorParams, busy: bool| WorkerDump {
+			worker,
+			pid: queue.workers.running[worker].handle.id(),
+			executor_params_hash: executor_params.hash(),
+			busy,
+		};
+		let mut expected = vec![
+			worker_dump(busy, executor_params(1), true),
+			worker_dump(idle, executor_params(2), false),
+		];
+		expected.sort_by_key(|worker| worker.pid);
+		let mut workers = dump.workers.clone();
+		workers.sort_by_key(|worker| worker.pid);
+		assert_eq!(workers, expected);
+
+		// The dump is serializable.
+		assert_eq!(QueueStateDump::decode(&mut &dump.encode()[..]).unwrap(), dump);
+	}
+
+	#[tokio::test]
+	async fn paused_queue_rejects_new_jobs_until_resumed() {
+		let TestQueue { mut queue, .. } = test_queue_with_metrics(1);
+		let worker = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		// The queue has nothing to execute, so it is drained at once.
+		let (drained_tx, mut drained_rx) = oneshot::channel();
+		handle_to_queue(&mut queue, ToQueue::Pause { drained: drained_tx });
+		assert!(queue.dump_state().paused);
+		assert_eq!(drained_rx.try_recv(), Ok(Some(())));
+
+		// New jobs are rejected without judging the candidate.
+		let mut result_rx = enqueue_and_watch(&mut queue, executor_params(1), Priority::Critical);
+		assert_matches!(
+			result_rx.try_recv(),
+			Ok(Some(Err(ValidationError::Internal(InternalValidationError::QueuePaused))))
+		);
+		assert!(queue.queue.is_empty());
+		assert!(queue.workers.running.get(worker).unwrap().idle.is_some());
+
+		// Once resumed, the queue assigns new jobs to its workers again.
+		handle_to_queue(&mut queue, ToQueue::Resume);
+		assert!(!queue.dump_state().paused);
+		enqueue(&mut queue, executor_params(1));
+		assert!(queue.workers.running.get(worker).unwrap().idle.is_none());
+	}
+
+	#[tokio::test]
+	async fn paused_queue_reports_drain_once_accepted_jobs_conclude() {
+		let TestQueue { mut queue, .. } = test_queue_with_metrics(1);
+		let worker = add_idle_worker(&mut queue, &executor_params(1)).await;
+		enqueue(&mut queue, executor_params(1));
+
+		let (drained_tx, mut drained_rx) = oneshot::channel();
+		handle_to_queue(&mut queue, ToQueue::Pause { drained: drained_tx });
+		assert_eq!(drained_rx.try_recv(), Ok(None));
+
+		// The accepted job concludes and its worker becomes idle.
+		let (idle, _handle) = spawn_dummy_worker(&queue.cache_path).await;
+		let response = WorkerInterfaceResponse {
+			worker_response: WorkerResponse {
+				job_response: JobResponse::InvalidCandidate("invalid".to_string()),
+				duration: Duration::from_secs(1),
+			},
+			idle_worker: idle,
+		};
+		let (result_tx, _result_rx) = oneshot::channel();
+		handle_job_finish(
+			&mut queue,
+			worker,
+			Ok(response),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+		queue.notify_if_drained();
+		assert_eq!(drained_rx.try_recv(), Ok(Some(())));
+	}
+
+	#[tokio::test]
+	async fn idle_worker_is_removed_immediately() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(1);
+		let worker = add_idle_worker(&mut queue, &executor_params(1)).await;
+
+		handle_to_queue(&mut queue, ToQueue::RemoveWorker(worker));
+
+		assert!(queue.workers.running.is_empty());
+		assert!(queue.retiring.is_empty());
+		assert_eq!(forced_worker_removals(&registry), 1);
+	}
+
+	#[tokio::test]
+	async fn busy_worker_is_removed_after_its_job_concludes() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(1);
+		let worker = add_idle_worker(&mut queue, &executor_params(1)).await;
+		enqueue(&mut queue, executor_params(1));
+
+		// The worker keeps executing its job.
+		handle_to_queue(&mut queue, ToQueue::RemoveWorker(worker));
+		assert!(queue.workers.running.contains_key(worker));
+		assert!(queue.retiring.contains(&worker));
+		assert_eq!(forced_worker_removals(&registry), 1);
+
+		// The job concludes successfully, but the worker is not reused.
+		let (idle, _handle) = spawn_dummy_worker(&queue.cache_path).await;
+		let response = WorkerInterfaceResponse {
+			worker_response: WorkerResponse {
+				job_response: JobResponse::InvalidCandidate("invalid".to_string()),
+				duration: Duration::from_secs(1),
+			},
+			idle_worker: idle,
+		};
+		let (result_tx, _result_rx) = oneshot::channel();
+		handle_job_finish(
+			&mut queue,
+			worker,
+			Ok(response),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+		assert!(queue.workers.running.is_empty());
+		assert!(queue.retiring.is_empty());
+	}
+
+	fn execute_outcomes(registry: &prometheus::Registry, priority: &str, outcome: &str) -> u64 {
+		registry
+			.gather()
+			.iter()
+			.filter(|family| family.get_name() == "polkadot_pvf_execute_outcome")
+			.flat_map(|family| family.get_metric())
+			.filter(|metric| {
+				let labels = metric.get_label();
+				labels.iter().any(|l| l.get_name() == "priority" && l.get_value() == priority) &&
+					labels.iter().any(|l| l.get_name() == "outcome" && l.get_value() == outcome)
+			})
+			.map(|metric| metric.get_counter().get_value() as u64)
+			.sum()
+	}
+
+	#[tokio::test]
+	async fn execute_outcomes_are_counted_by_priority() {
+		let TestQueue { mut queue, registry, .. } = test_queue_with_metrics(1);
+
+		// A backing job turns out to be invalid.
+		let (idle, _handle) = spawn_dummy_worker(&queue.cache_path).await;
+		let response = WorkerInterfaceResponse {
+			worker_response: WorkerResponse {
+				job_response: JobResponse::InvalidCandidate("invalid".to_string()),
+				duration: Duration::from_secs(1),
+			},
+			idle_worker: idle,
+		};
+		let (result_tx, _result_rx) = oneshot::channel();
+		handle_job_finish(
+			&mut queue,
+			Worker::default(),
+			Ok(response),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+
+		// The job of a dispute dies for an unknown reason.
+		let (result_tx, _result_rx) = oneshot::channel();
+		handle_job_finish(
+			&mut queue,
+			Worker::default(),
+			Err(job_died()),
+			artifact_id(1),
+			Priority::Critical,
+			result_tx,
+		);
+
+		assert_eq!(execute_outcomes(&registry, "normal", "invalid"), 1);
+		assert_eq!(execute_outcomes(&registry, "normal", "possibly-invalid"), 0);
+		assert_eq!(execute_outcomes(&registry, "critical", "possibly-invalid"), 1);
+		assert_eq!(execute_outcomes(&registry, "critical", "invalid"), 0);
+	}
+
+	#[test]
+	fn spawn_backoff_is_reproducible_with_fixed_seed() {
+		let delays = |seed| {
+			let mut backoff = SpawnBackoff::new(seed);
+			(0..6).map(|_| backoff.next_delay()).collect::<Vec<_>>()
+		};
+
+		// The same seed results in the same sequence of delays.
+		let sequence = delays(42);
+		assert_eq!(sequence, delays(42));
+		assert_ne!(sequence, delays(43));
+
+		// Every delay is the exponentially growing delay, shortened by up to a half.
+		for (attempt, delay) in sequence.into_iter().enumerate() {
+			let max_delay =
+				(SPAWN_RETRY_BASE_DELAY * 2u32.pow(attempt as u32)).min(SPAWN_RETRY_MAX_DELAY);
+			assert!(max_delay / 2 <= delay && delay <= max_delay, "{attempt}: {delay:?}");
+		}
+
+		// Queues with the same seed hand out the same backoffs to their spawns.
+		let (mut queue, _from_queue_rx) = test_queue(1);
+		let (mut other_queue, _other_from_queue_rx) = test_queue(1);
+		for _ in 0..3 {
+			assert_eq!(
+				queue.spawn_backoff().next_delay(),
+				other_queue.spawn_backoff().next_delay()
+			);
+		}
+	}
+
+	#[derive(Debug)]
+	struct JobDeathIsInvalid;
+
+	impl ExecuteErrorClassifier for JobDeathIsInvalid {
+		fn classify(&self, failure: ExecuteJobFailure) -> ValidationError {
+			match failure {
+				ExecuteJobFailure::WorkerError(WorkerError::JobDied { err, .. }) =>
+					ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err)),
+				failure => DefaultExecuteErrorClassifier.classify(failure),
+			}
+		}
+	}
+
+	fn finish_job_with_error(queue: &mut Queue, error: WorkerInterfaceError) -> ValidationError {
+		let (result_tx, mut result_rx) = oneshot::channel();
+		handle_job_finish(
+			queue,
+			Worker::default(),
+			Err(error),
+			artifact_id(1),
+			Priority::Normal,
+			result_tx,
+		);
+		assert_matches!(result_rx.try_recv(), Ok(Some(Err(err))) => err)
+	}
+
+	fn job_died() -> WorkerInterfaceError {
+		WorkerInterfaceError::WorkerError(WorkerError::JobDied {
+			err: "killed".to_string(),
+			job_pid: 1,
+		})
+	}
+
+	#[test]
+	fn job_death_is_possibly_invalid_by_default() {
+		let (mut queue, _from_queue_rx) = test_queue(1);
+
+		assert_matches!(
+			finish_job_with_error(&mut queue, job_died()),
+			ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousJobDeath(err)) => {
+				assert_eq!(err, "killed");
+			}
+		);
+	}
+
+	#[test]
+	fn error_classifier_may_reclassify_job_death() {
+		let (mut queue, _from_queue_rx) = test_queue(1);
+		queue.error_classifier = Arc::new(JobDeathIsInvalid);
+
+		assert_matches!(
+			finish_job_with_error(&mut queue, job_died()),
+			ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err)) => {
+				assert_eq!(err, "killed");
+			}
+		);
+		// Other failures are classified as usual.
+		assert_matches!(
+			finish_job_with_error(&mut queue, WorkerInterfaceError::HardTimeout),
+			ValidationError::Invalid(InvalidCandidate::HardTimeout)
+		);
+	}
+}
@@ -22,9 +22,14 @@
 
 use crate::{
 	artifacts::{ArtifactId, ArtifactPathId, ArtifactState, Artifacts, ArtifactsCleanupConfig},
-	execute::{self, PendingExecutionRequest},
+	execute::{
+		self, DefaultExecuteErrorClassifier, ExecuteErrorClassifier, PendingExecutionRequest,
+		QueueStateDump, WarmWorkersTarget, Worker,
+	},
 	metrics::Metrics,
-	prepare, Priority, SecurityStatus, ValidationError, LOG_TARGET,
+	prepare,
+	spawn_limiter::SpawnLimiter,
+	Priority, SecurityStatus, ValidationError, LOG_TARGET,
 };
 use always_assert::never;
 use futures::{
@@ -38,9 +43,11 @@ use polkadot_node_core_pvf_common::{
 };
 use polkadot_node_subsystem::{SubsystemError, SubsystemResult};
 use polkadot_parachain_primitives::primitives::ValidationResult;
+use polkadot_primitives::ExecutorParams;
 use std::{
 	collections::HashMap,
 	path::PathBuf,
+	sync::Arc,
 	time::{Duration, SystemTime},
 };
 
@@ -136,12 +143,67 @@ impl ValidationHost {
 			.await
 			.map_err(|_| "the inner loop hung up".to_string())
 	}
+
+	/// Requests a snapshot of the execute queue state, for debugging. The snapshot is sent to the
+	/// provided sender.
+	///
+	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
+	pub async fn dump_execute_queue_state(
+		&mut self,
+		reply_to: oneshot::Sender<QueueStateDump>,
+	) -> Result<(), String> {
+		self.to_host_tx
+			.send(ToHost::DumpExecuteQueueState { reply_to })
+			.await
+			.map_err(|_| "the inner loop hung up".to_string())
+	}
+
+	/// Requests the given execute worker, as identified in the [`QueueStateDump`], to be
+	/// removed, e.g. if it is suspected to be compromised. A busy worker is removed once its
+	/// current job concludes.
+	///
+	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
+	pub async fn remove_execute_worker(&mut self, worker: Worker) -> Result<(), String> {
+		self.to_host_tx
+			.send(ToHost::RemoveExecuteWorker(worker))
+			.await
+			.map_err(|_| "the inner loop hung up".to_string())
+	}
+
+	/// Stops the execute queue from accepting new jobs, e.g. before the worker binary is
+	/// replaced. The provided sender is notified once the jobs already accepted have concluded.
+	/// New execution requests fail until the queue is resumed.
+	///
+	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
+	pub async fn pause_execute_queue(
+		&mut self,
+		drained: oneshot::Sender<()>,
+	) -> Result<(), String> {
+		self.to_host_tx
+			.send(ToHost::PauseExecuteQueue { drained })
+			.await
+			.map_err(|_| "the inner loop hung up".to_string())
+	}
+
+	/// Resumes accepting new jobs by the execute queue after a pause.
+	///
+	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
+	pub async fn resume_execute_queue(&mut self) -> Result<(), String> {
+		self.to_host_tx
+			.send(ToHost::ResumeExecuteQueue)
+			.await
+			.map_err(|_| "the inner loop hung up".to_string())
+	}
 }
 
 enum ToHost {
 	PrecheckPvf { pvf: PvfPrepData, result_tx: PrecheckResultSender },
 	ExecutePvf(ExecutePvfInputs),
 	HeadsUp { active_pvfs: Vec<PvfPrepData> },
+	DumpExecuteQueueState { reply_to: oneshot::Sender<QueueStateDump> },
+	RemoveExecuteWorker(Worker),
+	PauseExecuteQueue { drained: oneshot::Sender<()> },
+	ResumeExecuteQueue,
 }
 
 struct ExecutePvfInputs {
@@ -161,6 +223,9 @@ pub struct Config {
 	pub node_version: Option<String>,
 	/// Whether the node is attempting to run as a secure validator.
 	pub secure_validator_mode: bool,
+	/// The maximum number of prepare and execute workers that can be spawning at the same time.
+	/// Unlimited by default.
+	pub workers_max_concurrent_spawns: Option<usize>,
 
 	/// The path to the program that can be used to spawn the prepare workers.
 	pub prepare_worker_program_path: PathBuf,
@@ -176,8 +241,26 @@ pub struct Config {
 	pub execute_worker_program_path: PathBuf,
 	/// The time allotted for an execute worker to spawn and report to the host.
 	pub execute_worker_spawn_timeout: Duration,
+	/// The time allotted for an execute worker, spawned for a job with the critical priority, to
+	/// spawn and report to the host.
+	pub execute_worker_critical_spawn_timeout: Duration,
+	/// The seed of the randomness used to spread the retries of failed execute worker spawns.
+	/// If `None`, the seed is taken from the OS entropy source.
+	pub execute_worker_spawn_retry_seed: Option<u64>,
+	/// If set, an execution job with the critical priority, which waits for a worker longer than
+	/// that, may kill a running job with the normal priority to free up a worker. The killed job
+	/// fails with an internal error. Disabled by default.
+	pub execute_preemption_threshold: Option<Duration>,
 	/// The maximum number of execute workers that can run at the same time.
 	pub execute_workers_max_num: usize,
+	/// The number of execute workers that are kept running even if there are no jobs for them,
+	/// so that a burst of jobs after a quiet period doesn't pay the spawn latency.
+	pub execute_workers_min_warm: usize,
+	/// Determines the executor parameters the warm execute workers are spawned with. The most
+	/// recently used ones by default.
+	pub execute_workers_warm_target: WarmWorkersTarget,
+	/// Determines which error is reported for a failed execution job.
+	pub execute_error_classifier: Arc<dyn ExecuteErrorClassifier>,
 }
 
 impl Config {
@@ -196,6 +279,7 @@ impl Config {
 			cache_path,
 			node_version,
 			secure_validator_mode,
+			workers_max_concurrent_spawns: None,
 
 			prepare_worker_program_path,
 			prepare_worker_spawn_timeout: Duration::from_secs(3),
@@ -204,9 +288,31 @@ impl Config {
 
 			execute_worker_program_path,
 			execute_worker_spawn_timeout: Duration::from_secs(3),
+			execute_worker_critical_spawn_timeout: Duration::from_secs(3),
+			execute_worker_spawn_retry_seed: None,
+			execute_preemption_threshold: None,
 			execute_workers_max_num,
+			execute_workers_min_warm: 0,
+			execute_workers_warm_target: WarmWorkersTarget::MostRecentlyUsed,
+			execute_error_classifier: Arc::new(DefaultExecuteErrorClassifier),
 		}
 	}
+
+	/// Sets the maximum number of execute workers to the given value or, if it is `None`, to the
+	/// value derived from the number of CPU cores of the host and the number of threads the jobs
+	/// with the given executor parameters are expected to keep busy.
+	pub fn with_execute_workers_max_num_hint(
+		mut self,
+		execute_workers_max_num: Option<usize>,
+		executor_params: &ExecutorParams,
+	) -> Self {
+		self.execute_workers_max_num = execute::derive_worker_capacity(
+			execute_workers_max_num,
+			num_cpus::get(),
+			execute::threads_per_job_hint(executor_params),
+		);
+		self
+	}
 }
 
 /// Start the validation host.
@@ -259,6 +365,10 @@ pub async fn start(
 
 	let validation_host = ValidationHost { to_host_tx, security_status: security_status.clone() };
 
+	let spawn_limiter = config
+		.workers_max_concurrent_spawns
+		.map_or_else(SpawnLimiter::unlimited, SpawnLimiter::new);
+
 	let (to_prepare_pool, from_prepare_pool, run_prepare_pool) = prepare::start_pool(
 		metrics.clone(),
 		config.prepare_worker_program_path.clone(),
@@ -272,6 +382,7 @@ pub async fn start(
 		metrics.clone(),
 		config.prepare_workers_soft_max_num,
 		config.prepare_workers_hard_max_num,
+		spawn_limiter.clone(),
 		config.cache_path.clone(),
 		to_prepare_pool,
 		from_prepare_pool,
@@ -282,9 +393,16 @@ pub async fn start(
 		config.execute_worker_program_path.to_owned(),
 		config.cache_path.clone(),
 		config.execute_workers_max_num,
+		config.execute_workers_min_warm,
+		config.execute_workers_warm_target,
+		spawn_limiter,
 		config.execute_worker_spawn_timeout,
+		config.execute_worker_critical_spawn_timeout,
+		config.execute_worker_spawn_retry_seed,
+		config.execute_preemption_threshold,
 		config.node_version,
 		security_status,
+		config.execute_error_classifier,
 	);
 
 	let (to_sweeper_tx, to_sweeper_rx) = mpsc::channel(100);
@@ -479,6 +597,13 @@ async fn handle_to_host(
 		},
 		ToHost::HeadsUp { active_pvfs } =>
 			handle_heads_up(artifacts, prepare_queue, active_pvfs).await?,
+		ToHost::DumpExecuteQueueState { reply_to } =>
+			send_execute(execute_queue, execute::ToQueue::DumpState { reply_to }).await?,
+		ToHost::RemoveExecuteWorker(worker) =>
+			send_execute(execute_queue, execute::ToQueue::RemoveWorker(worker)).await?,
+		ToHost::PauseExecuteQueue { drained } =>
+			send_execute(execute_queue, execute::ToQueue::Pause { drained }).await?,
+		ToHost::ResumeExecuteQueue => send_execute(execute_queue, execute::ToQueue::Resume).await?,
 	}
 
 	Ok(())
@@ -560,6 +685,7 @@ async fn handle_execute_pvf(
 								exec_timeout,
 								params,
 								executor_params,
+								priority,
 								result_tx,
 							},
 						},
@@ -589,6 +715,7 @@ async fn handle_execute_pvf(
 							exec_timeout,
 							params,
 							executor_params,
+							priority,
 							result_tx,
 						},
 					)
@@ -598,7 +725,13 @@ async fn handle_execute_pvf(
 			ArtifactState::Preparing { .. } => {
 				awaiting_prepare.add(
 					artifact_id,
-					PendingExecutionRequest { exec_timeout, params, executor_params, result_tx },
+					PendingExecutionRequest {
+						exec_timeout,
+						params,
+						executor_params,
+						priority,
+						result_tx,
+					},
 				);
 			},
 			ArtifactState::FailedToProcess { last_time_failed, num_failures, error } => {
@@ -629,6 +762,7 @@ async fn handle_execute_pvf(
 							exec_timeout,
 							params,
 							executor_params,
+							priority,
 							result_tx,
 						},
 					)
@@ -648,7 +782,7 @@ async fn handle_execute_pvf(
 			pvf,
 			priority,
 			artifact_id,
-			PendingExecutionRequest { exec_timeout, params, executor_params, result_tx },
+			PendingExecutionRequest { exec_timeout, params, executor_params, priority, result_tx },
 		)
 		.await?;
 	}
@@ -770,7 +904,7 @@ async fn handle_prepare_done(
 	// It's finally time to dispatch all the execution requests that were waiting for this artifact
 	// to be prepared.
 	let pending_requests = awaiting_prepare.take(&artifact_id);
-	for PendingExecutionRequest { exec_timeout, params, executor_params, result_tx } in
+	for PendingExecutionRequest { exec_timeout, params, executor_params, priority, result_tx } in
 		pending_requests
 	{
 		if result_tx.is_canceled() {
@@ -795,6 +929,7 @@ async fn handle_prepare_done(
 					exec_timeout,
 					params,
 					executor_params,
+					priority,
 					result_tx,
 				},
 			},
@@ -1219,6 +1354,48 @@ pub(crate) mod tests {
 		test.poll_ensure_to_sweeper_is_empty().await;
 	}
 
+	#[tokio::test]
+	async fn execute_queue_state_dump_is_forwarded() {
+		let mut test = Builder::default().build();
+		let mut host = test.host_handle();
+
+		let (reply_to, _reply_rx) = oneshot::channel();
+		host.dump_execute_queue_state(reply_to).await.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_execute_queue().await,
+			execute::ToQueue::DumpState { .. }
+		);
+	}
+
+	#[tokio::test]
+	async fn execute_worker_removal_is_forwarded() {
+		let mut test = Builder::default().build();
+		let mut host = test.host_handle();
+
+		let worker = Worker::default();
+		host.remove_execute_worker(worker).await.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_execute_queue().await,
+			execute::ToQueue::RemoveWorker(removed) if removed == worker
+		);
+	}
+
+	#[tokio::test]
+	async fn execute_queue_pause_and_resume_are_forwarded() {
+		let mut test = Builder::default().build();
+		let mut host = test.host_handle();
+
+		let (drained, _drained_rx) = oneshot::channel();
+		host.pause_execute_queue(drained).await.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_execute_queue().await,
+			execute::ToQueue::Pause { .. }
+		);
+
+		host.resume_execute_queue().await.unwrap();
+		assert_matches!(test.poll_and_recv_to_execute_queue().await, execute::ToQueue::Resume);
+	}
+
 	#[tokio::test]
 	async fn execute_pvf_requests() {
 		let mut test = Builder::default().build();
@@ -99,12 +99,17 @@ mod prepare;
 mod priority;
 #[cfg(target_os = "linux")]
 mod security;
+mod spawn_limiter;
 mod worker_interface;
 
 #[cfg(feature = "test-utils")]
 pub mod testing;
 
 pub use error::{InvalidCandidate, PossiblyInvalidError, ValidationError};
+pub use execute::{
+	DefaultExecuteErrorClassifier, ExecuteErrorClassifier, ExecuteJobFailure, PendingJobDump,
+	QueueStateDump, WarmWorkersTarget, Worker as ExecuteWorker, WorkerDump,
+};
 pub use host::{
 	start, Config, ValidationHost, EXECUTE_BINARY_NAME, HOST_MESSAGE_QUEUE_SIZE,
 	PREPARE_BINARY_NAME,
@@ -16,6 +16,7 @@
 
 //! Prometheus metrics related to the validation host.
 
+use crate::priority::Priority;
 use polkadot_node_core_pvf_common::prepare::MemoryStats;
 use polkadot_node_metrics::metrics::{self, prometheus};
 
@@ -48,6 +49,25 @@ impl Metrics {
 		}
 	}
 
+	/// When the spawning of an execute worker started for the given reason.
+	pub(crate) fn execute_worker_begin_spawn(&self, reason: WorkerSpawnReason) {
+		self.execute_worker().on_begin_spawn();
+		if let Some(metrics) = &self.0 {
+			metrics
+				.execute_worker_spawning_by_reason
+				.with_label_values(&[reason.as_label()])
+				.inc();
+		}
+	}
+
+	/// When an execute worker spawned for the given reason successfully.
+	pub(crate) fn execute_worker_spawned(&self, reason: WorkerSpawnReason) {
+		self.execute_worker().on_spawned();
+		if let Some(metrics) = &self.0 {
+			metrics.execute_worker_spawned_by_reason.with_label_values(&[reason.as_label()]).inc();
+		}
+	}
+
 	/// When execution pipeline had a new item enqueued.
 	pub(crate) fn execute_enqueued(&self) {
 		if let Some(metrics) = &self.0 {
@@ -62,6 +82,23 @@ impl Metrics {
 		}
 	}
 
+	/// When execution pipeline concluded a job of the given priority with the given outcome.
+	pub(crate) fn execute_concluded(&self, priority: Priority, outcome: ExecuteOutcome) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.execute_outcome
+				.with_label_values(&[priority_label(priority), outcome.as_label()])
+				.inc();
+		}
+	}
+
+	/// When an operator requested the execution pipeline to remove a worker.
+	pub(crate) fn execute_forced_worker_removal(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.execute_forced_worker_removal.inc();
+		}
+	}
+
 	/// Time between sending preparation request to a worker to having the response.
 	pub(crate) fn time_preparation(
 		&self,
@@ -112,10 +149,14 @@ struct MetricsInner {
 	worker_spawning: prometheus::CounterVec<prometheus::U64>,
 	worker_spawned: prometheus::CounterVec<prometheus::U64>,
 	worker_retired: prometheus::CounterVec<prometheus::U64>,
+	execute_worker_spawning_by_reason: prometheus::CounterVec<prometheus::U64>,
+	execute_worker_spawned_by_reason: prometheus::CounterVec<prometheus::U64>,
 	prepare_enqueued: prometheus::Counter<prometheus::U64>,
 	prepare_concluded: prometheus::Counter<prometheus::U64>,
 	execute_enqueued: prometheus::Counter<prometheus::U64>,
 	execute_finished: prometheus::Counter<prometheus::U64>,
+	execute_forced_worker_removal: prometheus::Counter<prometheus::U64>,
+	execute_outcome: prometheus::CounterVec<prometheus::U64>,
 	preparation_time: prometheus::Histogram,
 	execution_time: prometheus::Histogram,
 	execution_queued_time: prometheus::Histogram,
@@ -164,6 +205,26 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			execute_worker_spawning_by_reason: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_execute_worker_spawning_by_reason",
+						"The total number of execute workers began to spawn, by the reason of spawning",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			execute_worker_spawned_by_reason: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_execute_worker_spawned_by_reason",
+						"The total number of execute workers spawned successfully, by the reason of spawning",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
 			prepare_enqueued: prometheus::register(
 				prometheus::Counter::new(
 					"polkadot_pvf_prepare_enqueued",
@@ -192,6 +253,23 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			execute_forced_worker_removal: prometheus::register(
+				prometheus::Counter::new(
+					"polkadot_pvf_execute_forced_worker_removal",
+					"The total number of execute workers removed on request of an operator"
+				)?,
+				registry,
+			)?,
+			execute_outcome: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_execute_outcome",
+						"The total number of jobs concluded in the execution pipeline, by priority and outcome",
+					),
+					&["priority", "outcome"],
+				)?,
+				registry,
+			)?,
 			preparation_time: prometheus::register(
 				prometheus::Histogram::with_opts(
 					prometheus::HistogramOpts::new(
@@ -342,6 +420,58 @@ impl WorkerFlavor {
 	}
 }
 
+/// The reason an execute worker is spawned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum WorkerSpawnReason {
+	/// There are jobs waiting and the queue can afford one more worker.
+	CapacityGrowth,
+	/// An idle worker with incompatible executor parameters was killed to make room for this one.
+	RespawnAfterKill,
+	/// The queue has less workers than the configured minimum of warm workers.
+	WarmUp,
+}
+
+impl WorkerSpawnReason {
+	fn as_label(&self) -> &'static str {
+		match *self {
+			WorkerSpawnReason::CapacityGrowth => "capacity-growth",
+			WorkerSpawnReason::RespawnAfterKill => "respawn-after-kill",
+			WorkerSpawnReason::WarmUp => "warm-up",
+		}
+	}
+}
+
+/// The outcome of a job concluded by the execution pipeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ExecuteOutcome {
+	/// The candidate is valid.
+	Ok,
+	/// The candidate is invalid.
+	Invalid,
+	/// The candidate is possibly invalid, the execution may be retried.
+	PossiblyInvalid,
+	/// The job failed for a reason which can't be attributed to the candidate.
+	Internal,
+}
+
+impl ExecuteOutcome {
+	fn as_label(&self) -> &'static str {
+		match *self {
+			ExecuteOutcome::Ok => "ok",
+			ExecuteOutcome::Invalid => "invalid",
+			ExecuteOutcome::PossiblyInvalid => "possibly-invalid",
+			ExecuteOutcome::Internal => "internal",
+		}
+	}
+}
+
+fn priority_label(priority: Priority) -> &'static str {
+	match priority {
+		Priority::Normal => "normal",
+		Priority::Critical => "critical",
+	}
+}
+
 pub(crate) struct WorkerRelatedMetrics<'a> {
 	metrics: &'a Metrics,
 	flavor: WorkerFlavor,
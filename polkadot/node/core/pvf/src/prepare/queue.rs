@@ -17,9 +17,19 @@
 //! A queue that handles requests for PVF preparation.
 
 use super::pool::{self, Worker};
-use crate::{artifacts::ArtifactId, metrics::Metrics, Priority, LOG_TARGET};
+use crate::{
+	artifacts::ArtifactId,
+	metrics::Metrics,
+	spawn_limiter::{SpawnLimiter, SpawnPermit},
+	Priority, LOG_TARGET,
+};
 use always_assert::{always, never};
-use futures::{channel::mpsc, stream::StreamExt as _, Future, SinkExt};
+use futures::{
+	channel::{mpsc, oneshot},
+	future::{Fuse, FusedFuture as _},
+	stream::StreamExt as _,
+	Future, FutureExt as _, SinkExt,
+};
 use polkadot_node_core_pvf_common::{error::PrepareResult, pvf::PvfPrepData};
 use std::{
 	collections::{HashMap, VecDeque},
@@ -125,6 +135,10 @@ impl Unscheduled {
 		self.normal.is_empty() && self.critical.is_empty()
 	}
 
+	fn len(&self) -> usize {
+		self.normal.len() + self.critical.len()
+	}
+
 	fn next(&mut self) -> Option<Job> {
 		let mut check = |prio: Priority| self.queue_mut(prio).pop_front();
 		check(Priority::Critical).or_else(|| check(Priority::Normal))
@@ -151,6 +165,12 @@ struct Queue {
 	workers: slotmap::SparseSecondaryMap<Worker, WorkerData>,
 	/// The number of workers requested to spawn but not yet spawned.
 	spawn_inflight: usize,
+	/// The limit of concurrent worker spawns, shared with the execute queue.
+	spawn_limiter: SpawnLimiter,
+	/// The permits held by the workers requested to spawn but not yet spawned.
+	spawn_permits: Vec<SpawnPermit>,
+	/// Resolves once a spawn permit is released, if a spawn has been deferred for the lack of it.
+	spawn_permit_released: Fuse<oneshot::Receiver<()>>,
 
 	/// The jobs that are not yet scheduled. These are waiting until the next `poll` where they are
 	/// processed all at once.
@@ -165,6 +185,7 @@ impl Queue {
 		metrics: Metrics,
 		soft_capacity: usize,
 		hard_capacity: usize,
+		spawn_limiter: SpawnLimiter,
 		cache_path: PathBuf,
 		to_queue_rx: mpsc::Receiver<ToQueue>,
 		from_queue_tx: mpsc::UnboundedSender<FromQueue>,
@@ -179,6 +200,9 @@ impl Queue {
 			from_pool_rx,
 			cache_path,
 			spawn_inflight: 0,
+			spawn_limiter,
+			spawn_permits: Vec::new(),
+			spawn_permit_released: Fuse::terminated(),
 			limits: Limits { hard_capacity, soft_capacity },
 			jobs: slotmap::SlotMap::with_key(),
 			unscheduled: Unscheduled::default(),
@@ -203,6 +227,8 @@ impl Queue {
 					break_if_fatal!(handle_to_queue(&mut self, to_queue).await),
 				from_pool = self.from_pool_rx.select_next_some() =>
 					break_if_fatal!(handle_from_pool(&mut self, from_pool).await),
+				_ = &mut self.spawn_permit_released =>
+					break_if_fatal!(handle_spawn_permit_released(&mut self).await),
 			}
 		}
 	}
@@ -281,6 +307,8 @@ async fn handle_from_pool(queue: &mut Queue, from_pool: pool::FromPool) -> Resul
 async fn handle_worker_spawned(queue: &mut Queue, worker: Worker) -> Result<(), Fatal> {
 	queue.workers.insert(worker, WorkerData::default());
 	queue.spawn_inflight -= 1;
+	// The permits are interchangeable, it doesn't matter which one is released.
+	queue.spawn_permits.pop();
 
 	if let Some(job) = queue.unscheduled.next() {
 		assign(queue, worker, job).await?;
@@ -408,14 +436,42 @@ async fn handle_worker_rip(queue: &mut Queue, worker: Worker) -> Result<(), Fata
 	Ok(())
 }
 
-/// Spawns an extra worker if possible.
-async fn spawn_extra_worker(queue: &mut Queue, critical: bool) -> Result<(), Fatal> {
-	if queue
+/// Spawns an extra worker if possible. Returns `true` if the worker is requested to spawn.
+///
+/// If the limit of concurrent spawns, shared with the execute queue, is reached, the spawn is
+/// deferred until a spawn permit is released.
+async fn spawn_extra_worker(queue: &mut Queue, critical: bool) -> Result<bool, Fatal> {
+	if !queue
 		.limits
 		.can_afford_one_more(queue.workers.len() + queue.spawn_inflight, critical)
 	{
-		queue.spawn_inflight += 1;
-		send_pool(&mut queue.to_pool_tx, pool::ToPool::Spawn).await?;
+		return Ok(false)
+	}
+
+	let permit = if let Some(permit) = queue.spawn_limiter.try_acquire() {
+		permit
+	} else {
+		gum::debug!(target: LOG_TARGET, "deferring a prepare worker spawn");
+		if queue.spawn_permit_released.is_terminated() {
+			queue.spawn_permit_released = queue.spawn_limiter.notify_on_release().fuse();
+		}
+		return Ok(false)
+	};
+
+	queue.spawn_permits.push(permit);
+	queue.spawn_inflight += 1;
+	send_pool(&mut queue.to_pool_tx, pool::ToPool::Spawn).await?;
+
+	Ok(true)
+}
+
+/// Requests the spawns deferred for the lack of a spawn permit, as long as the permits suffice.
+async fn handle_spawn_permit_released(queue: &mut Queue) -> Result<(), Fatal> {
+	while queue.unscheduled.len() > queue.spawn_inflight {
+		let critical = !queue.unscheduled.critical.is_empty();
+		if !spawn_extra_worker(queue, critical).await? {
+			break
+		}
 	}
 
 	Ok(())
@@ -463,6 +519,7 @@ pub fn start(
 	metrics: Metrics,
 	soft_capacity: usize,
 	hard_capacity: usize,
+	spawn_limiter: SpawnLimiter,
 	cache_path: PathBuf,
 	to_pool_tx: mpsc::Sender<pool::ToPool>,
 	from_pool_rx: mpsc::UnboundedReceiver<pool::FromPool>,
@@ -474,6 +531,7 @@ pub fn start(
 		metrics,
 		soft_capacity,
 		hard_capacity,
+		spawn_limiter,
 		cache_path,
 		to_queue_rx,
 		from_queue_tx,
@@ -546,6 +604,7 @@ mod tests {
 				Metrics::default(),
 				soft_capacity,
 				hard_capacity,
+				SpawnLimiter::unlimited(),
 				tempdir.path().to_owned().into(),
 				to_pool_tx,
 				from_pool_rx,
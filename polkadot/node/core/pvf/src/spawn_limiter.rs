@@ -0,0 +1,125 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A host-wide limit of concurrent worker spawns, shared by the prepare and execute queues.
+
+use futures::channel::oneshot;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Limits the number of workers being spawned at the same time, so that spawn storms of the
+/// prepare and execute queues don't oversubscribe the CPU.
+///
+/// Clones of the limiter share the same limit.
+#[derive(Clone, Debug)]
+pub struct SpawnLimiter {
+	inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+	/// The number of spawns that may be started right now.
+	available: usize,
+	/// The subscribers waiting for a permit to be released.
+	waiters: Vec<oneshot::Sender<()>>,
+}
+
+impl SpawnLimiter {
+	/// Creates a limiter allowing up to `max_concurrent_spawns` spawns at the same time.
+	pub fn new(max_concurrent_spawns: usize) -> Self {
+		Self {
+			inner: Arc::new(Mutex::new(Inner {
+				available: max_concurrent_spawns,
+				waiters: Vec::new(),
+			})),
+		}
+	}
+
+	/// Creates a limiter which never defers a spawn.
+	pub fn unlimited() -> Self {
+		Self::new(usize::MAX)
+	}
+
+	/// Takes a permit to spawn a worker, if the limit is not reached. The permit should be held
+	/// until the worker is spawned.
+	pub fn try_acquire(&self) -> Option<SpawnPermit> {
+		let mut inner = self.lock();
+		if inner.available == 0 {
+			return None
+		}
+		inner.available -= 1;
+		Some(SpawnPermit { limiter: self.clone() })
+	}
+
+	/// Returns a receiver which resolves once a permit may be acquired. It resolves immediately if
+	/// a permit may be acquired already.
+	///
+	/// Several subscribers may compete for a released permit; the ones which fail to acquire it
+	/// are expected to subscribe again.
+	pub fn notify_on_release(&self) -> oneshot::Receiver<()> {
+		let (tx, rx) = oneshot::channel();
+		let mut inner = self.lock();
+		if inner.available > 0 {
+			let _ = tx.send(());
+		} else {
+			inner.waiters.push(tx);
+		}
+		rx
+	}
+
+	fn release(&self) {
+		let mut inner = self.lock();
+		inner.available += 1;
+		for waiter in inner.waiters.drain(..) {
+			// The subscriber may have gone away, that's fine
+			let _ = waiter.send(());
+		}
+	}
+
+	fn lock(&self) -> MutexGuard<'_, Inner> {
+		self.inner.lock().expect("the lock is never held across a panic; qed")
+	}
+}
+
+/// A permit to spawn a worker. The permit is released once dropped.
+#[derive(Debug)]
+pub struct SpawnPermit {
+	limiter: SpawnLimiter,
+}
+
+impl Drop for SpawnPermit {
+	fn drop(&mut self) {
+		self.limiter.release();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn released_permit_wakes_up_waiters() {
+		let limiter = SpawnLimiter::new(1);
+		let permit = limiter.try_acquire().unwrap();
+		assert!(limiter.try_acquire().is_none());
+
+		let mut released = limiter.notify_on_release();
+		assert_eq!(released.try_recv(), Ok(None));
+
+		drop(permit);
+		assert_eq!(released.try_recv(), Ok(Some(())));
+		assert!(limiter.try_acquire().is_some());
+	}
+}
@@ -284,6 +284,30 @@ impl WorkerHandle {
 	}
 }
 
+/// Spawns a process that keeps running but never acts as a worker. Allows the queues' unit tests
+/// to have running workers without spawning real ones.
+#[cfg(test)]
+pub(crate) async fn spawn_dummy_worker(cache_path: &Path) -> (IdleWorker, WorkerHandle) {
+	let worker_dir = WorkerDir::new("dummy", cache_path).await.unwrap();
+	let (stream, _) = UnixStream::pair().unwrap();
+	let mut child = process::Command::new("sleep")
+		.arg("600")
+		.stdout(std::process::Stdio::piped())
+		.kill_on_drop(true)
+		.spawn()
+		.unwrap();
+	let child_id = child.id().unwrap();
+	let stdout = child.stdout.take().unwrap();
+	let handle = WorkerHandle {
+		child,
+		child_id,
+		stdout,
+		program: PathBuf::from("sleep"),
+		drop_box: vec![0; 8192].into_boxed_slice(),
+	};
+	(IdleWorker { stream, pid: child_id, worker_dir }, handle)
+}
+
 impl futures::Future for WorkerHandle {
 	type Output = ();
 
@@ -2133,6 +2133,7 @@ impl pallet_broker::Config for Runtime {
 	type TimeslicePeriod = ConstU32<2>;
 	type MaxLeasedCores = ConstU32<5>;
 	type MaxReservedCores = ConstU32<5>;
+	type MaxDropBatchSize = ConstU32<16>;
 	type Coretime = CoretimeProvider;
 	type ConvertBalance = traits::Identity;
 	type WeightInfo = ();
@@ -17,6 +17,7 @@
 
 use super::*;
 use frame_support::{
+	dispatch::PostDispatchInfo,
 	pallet_prelude::{DispatchResult, *},
 	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
 };
@@ -458,6 +459,27 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_drop_batch<Item>(
+		items: impl IntoIterator<Item = Item>,
+		item_weight: Weight,
+		drop: impl Fn(Item) -> DispatchResult,
+	) -> DispatchResultWithPostInfo {
+		let (mut dropped, mut skipped) = (0u64, 0u64);
+		for item in items {
+			match drop(item) {
+				Ok(()) => dropped.saturating_inc(),
+				Err(_) => skipped.saturating_inc(),
+			}
+		}
+
+		// The fee is only waived if every item has been dropped.
+		let pays_fee = if dropped > 0 && skipped == 0 { Pays::No } else { Pays::Yes };
+		Ok(PostDispatchInfo {
+			actual_weight: Some(item_weight.saturating_mul(dropped.saturating_add(skipped))),
+			pays_fee,
+		})
+	}
+
 	pub(crate) fn do_swap_leases(id: TaskId, other: TaskId) -> DispatchResult {
 		let mut id_leases_count = 0;
 		let mut other_leases_count = 0;
@@ -496,4 +518,35 @@ impl<T: Config> Pallet<T> {
 		let now = frame_system::Pallet::<T>::block_number();
 		Ok(Self::sale_price(&sale, now))
 	}
+
+	/// Checks whether the given core can be renewed in the ongoing sale and if not, returns the
+	/// reason why.
+	///
+	/// Performs the same checks as `renew`, without altering any state.
+	pub fn can_renew(core: CoreIndex) -> Result<(), RenewalIneligibilityReason> {
+		use RenewalIneligibilityReason::*;
+
+		ensure!(Configuration::<T>::exists(), Uninitialized);
+		let status = Status::<T>::get().ok_or(Uninitialized)?;
+		let sale = SaleInfo::<T>::get().ok_or(NoSales)?;
+		ensure!(sale.first_core < status.core_count, Unavailable);
+
+		// The renewal registers the potential renewal of the core for the region following the
+		// one being sold, so the core which has it already has a workload for the sold region.
+		let renewal = PotentialRenewals::<T>::get(PotentialRenewalId {
+			core,
+			when: sale.region_begin,
+		});
+		let renewed_id = PotentialRenewalId { core, when: sale.region_end };
+		ensure!(
+			renewal.is_some() || !PotentialRenewals::<T>::contains_key(renewed_id),
+			AlreadyRenewed
+		);
+		ensure!(sale.cores_sold < sale.cores_offered, SoldOut);
+
+		let record = renewal.ok_or(NotAllowed)?;
+		ensure!(record.completion.complete().is_some(), IncompleteAssignment);
+
+		Ok(())
+	}
 }
@@ -114,6 +114,10 @@ pub mod pallet {
 		/// Maximum number of system cores.
 		#[pallet::constant]
 		type MaxReservedCores: Get<u32>;
+
+		/// Maximum number of expired items which may be dropped by a single batch drop call.
+		#[pallet::constant]
+		type MaxDropBatchSize: Get<u32>;
 	}
 
 	/// The current configuration of this pallet.
@@ -784,6 +788,78 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Drop a batch of expired Regions from the chain.
+		///
+		/// Regions which cannot be dropped are skipped. The call is only free if all Regions have
+		/// been dropped.
+		///
+		/// - `origin`: Can be any kind of origin.
+		/// - `region_ids`: The Regions which have expired.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::drop_region().saturating_mul(region_ids.len() as u64))]
+		pub fn drop_regions(
+			_origin: OriginFor<T>,
+			region_ids: BoundedVec<RegionId, T::MaxDropBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			Self::do_drop_batch(region_ids, T::WeightInfo::drop_region(), Self::do_drop_region)
+		}
+
+		/// Drop a batch of expired Instantaneous Pool Contribution records from the chain.
+		///
+		/// Records which cannot be dropped are skipped. The call is only free if all records have
+		/// been dropped.
+		///
+		/// - `origin`: Can be any kind of origin.
+		/// - `region_ids`: The Regions identifying the Pool Contributions which have expired.
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			T::WeightInfo::drop_contribution().saturating_mul(region_ids.len() as u64)
+		)]
+		pub fn drop_contributions(
+			_origin: OriginFor<T>,
+			region_ids: BoundedVec<RegionId, T::MaxDropBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			Self::do_drop_batch(
+				region_ids,
+				T::WeightInfo::drop_contribution(),
+				Self::do_drop_contribution,
+			)
+		}
+
+		/// Drop a batch of expired Instantaneous Pool History records from the chain.
+		///
+		/// Records which cannot be dropped are skipped. The call is only free if all records have
+		/// been dropped.
+		///
+		/// - `origin`: Can be any kind of origin.
+		/// - `whens`: The times of the Pool History records which have expired.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::drop_history().saturating_mul(whens.len() as u64))]
+		pub fn drop_histories(
+			_origin: OriginFor<T>,
+			whens: BoundedVec<Timeslice, T::MaxDropBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			Self::do_drop_batch(whens, T::WeightInfo::drop_history(), Self::do_drop_history)
+		}
+
+		/// Drop a batch of expired Allowed Renewal records from the chain.
+		///
+		/// Records which cannot be dropped are skipped. The call is only free if all records have
+		/// been dropped.
+		///
+		/// - `origin`: Can be any kind of origin.
+		/// - `renewals`: The cores and the timeslices to which the expired renewals refer.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::drop_renewal().saturating_mul(renewals.len() as u64))]
+		pub fn drop_renewals(
+			_origin: OriginFor<T>,
+			renewals: BoundedVec<(CoreIndex, Timeslice), T::MaxDropBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			Self::do_drop_batch(renewals, T::WeightInfo::drop_renewal(), |(core, when)| {
+				Self::do_drop_renewal(core, when)
+			})
+		}
+
 		/// Request a change to the number of cores available for scheduling work.
 		///
 		/// - `origin`: Must be Root or pass `AdminOrigin`.
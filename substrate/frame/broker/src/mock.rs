@@ -194,6 +194,7 @@ impl crate::Config for Test {
 	type TimeslicePeriod = ConstU64<2>;
 	type MaxLeasedCores = ConstU32<5>;
 	type MaxReservedCores = ConstU32<5>;
+	type MaxDropBatchSize = ConstU32<16>;
 	type Coretime = TestCoretimeProvider;
 	type ConvertBalance = Identity;
 	type WeightInfo = ();
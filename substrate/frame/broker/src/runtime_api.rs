@@ -17,15 +17,22 @@
 
 //! Runtime API definition for the FRAME Broker pallet.
 
+use crate::{CoreIndex, RenewalIneligibilityReason};
 use codec::Codec;
 use sp_runtime::DispatchError;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait BrokerApi<Balance>
 	where
 		Balance: Codec
 	{
 		/// If there is an ongoing sale returns the current price of a core.
 		fn sale_price() -> Result<Balance, DispatchError>;
+
+		/// Checks whether the given core can be renewed in the ongoing sale and if not, returns
+		/// the reason why.
+		#[api_version(2)]
+		fn can_renew(core: CoreIndex) -> Result<(), RenewalIneligibilityReason>;
 	}
 }
@@ -20,6 +20,7 @@
 use crate::{core_mask::*, mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok,
+	dispatch::Pays,
 	traits::nonfungible::{Inspect as NftInspect, Mutate, Transfer},
 	BoundedVec,
 };
@@ -86,6 +87,75 @@ fn drop_renewal_works() {
 	});
 }
 
+#[test]
+fn drop_regions_skips_regions_which_cannot_be_dropped() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 2));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let other_region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_assign(region, Some(1), 1001, Provisional));
+		let unknown_region = RegionId { begin: 0, core: 5, mask: CoreMask::complete() };
+		let batch: BoundedVec<_, _> =
+			vec![region, other_region, unknown_region].try_into().unwrap();
+
+		// Nothing can be dropped yet, so the call is paid for.
+		advance_to(11);
+		let info = Broker::drop_regions(RuntimeOrigin::signed(1), batch.clone()).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		assert_eq!(Regions::<Test>::iter().count(), 2);
+
+		// The call is free if all regions are dropped.
+		advance_to(12);
+		let info =
+			Broker::drop_regions(RuntimeOrigin::signed(1), vec![region].try_into().unwrap())
+				.unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(Regions::<Test>::iter().count(), 1);
+
+		// Expired region is dropped and the unknown one is skipped, so the call is paid for.
+		let batch: BoundedVec<_, _> = vec![other_region, unknown_region].try_into().unwrap();
+		let info = Broker::drop_regions(RuntimeOrigin::signed(1), batch).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		assert_eq!(
+			info.actual_weight,
+			Some(<Test as Config>::WeightInfo::drop_region().saturating_mul(2))
+		);
+		assert_eq!(Regions::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn drop_renewals_skips_renewals_which_cannot_be_dropped() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_assign(region, Some(1), 1001, Final));
+		advance_to(12);
+		assert_eq!(PotentialRenewals::<Test>::iter().count(), 1);
+		let renewal = (region.core, region.begin + 3);
+		// A renewal which is not yet expired and a renewal which doesn't exist.
+		let future_renewal = (region.core, region.begin + 100);
+		let unknown_renewal = (region.core + 1, region.begin + 3);
+		let batch: BoundedVec<_, _> =
+			vec![future_renewal, renewal, unknown_renewal].try_into().unwrap();
+
+		// Some renewals cannot be dropped, so the call is paid for.
+		let info = Broker::drop_renewals(RuntimeOrigin::signed(1), batch).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		assert_eq!(
+			info.actual_weight,
+			Some(<Test as Config>::WeightInfo::drop_renewal().saturating_mul(3))
+		);
+		assert_eq!(PotentialRenewals::<Test>::iter().count(), 0);
+		System::assert_has_event(
+			Event::<Test>::PotentialRenewalDropped { core: region.core, when: region.begin + 3 }
+				.into(),
+		);
+	});
+}
+
 #[test]
 fn drop_contribution_works() {
 	TestExt::new().contribution_timeout(3).endow(1, 1000).execute_with(|| {
@@ -108,6 +178,33 @@ fn drop_contribution_works() {
 	});
 }
 
+#[test]
+fn drop_contributions_skips_contributions_which_cannot_be_dropped() {
+	TestExt::new().contribution_timeout(3).endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_pool(region, Some(1), 1, Final));
+		let unknown_region = RegionId { begin: 0, core: 5, mask: CoreMask::complete() };
+		let batch: BoundedVec<_, _> = vec![region, unknown_region].try_into().unwrap();
+		let batch_weight = <Test as Config>::WeightInfo::drop_contribution().saturating_mul(2);
+
+		// Nothing can be dropped yet, so the call is paid for.
+		advance_to(19);
+		let info = Broker::drop_contributions(RuntimeOrigin::signed(1), batch.clone()).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		assert_eq!(info.actual_weight, Some(batch_weight));
+		assert_eq!(InstaPoolContribution::<Test>::iter().count(), 1);
+
+		// Expired contribution is dropped and the unknown one is skipped.
+		advance_to(20);
+		let info = Broker::drop_contributions(RuntimeOrigin::signed(1), batch).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		assert_eq!(info.actual_weight, Some(batch_weight));
+		assert_eq!(InstaPoolContribution::<Test>::iter().count(), 0);
+	});
+}
+
 #[test]
 fn drop_history_works() {
 	TestExt::new()
@@ -172,6 +269,48 @@ fn drop_history_works() {
 		});
 }
 
+#[test]
+fn drop_histories_skips_histories_which_cannot_be_dropped() {
+	TestExt::new()
+		.contribution_timeout(4)
+		.endow(1, 1000)
+		.endow(2, 30)
+		.execute_with(|| {
+			assert_ok!(Broker::do_start_sales(100, 1));
+			advance_to(2);
+			let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+			assert_ok!(Broker::do_pool(region, Some(1), 1, Final));
+			assert_ok!(Broker::do_purchase_credit(2, 30, 2));
+			for block in [8, 10, 12] {
+				advance_to(block);
+				assert_ok!(TestCoretimeProvider::spend_instantaneous(2, 10));
+			}
+			let batch: BoundedVec<_, _> = vec![region.begin, u32::MAX].try_into().unwrap();
+			let batch_weight = <Test as Config>::WeightInfo::drop_history().saturating_mul(2);
+
+			// Nothing can be dropped yet, so the call is paid for.
+			advance_to(17);
+			let info = Broker::drop_histories(RuntimeOrigin::signed(1), batch.clone()).unwrap();
+			assert_eq!(info.pays_fee, Pays::Yes);
+			assert_eq!(info.actual_weight, Some(batch_weight));
+			advance_to(18);
+			assert_eq!(InstaPoolHistory::<Test>::iter().count(), 6);
+
+			// Expired history is dropped and the still valid one is skipped.
+			let info = Broker::drop_histories(RuntimeOrigin::signed(1), batch).unwrap();
+			assert_eq!(info.pays_fee, Pays::Yes);
+			assert_eq!(info.actual_weight, Some(batch_weight));
+			assert_eq!(InstaPoolHistory::<Test>::iter().count(), 5);
+
+			// The call is free if all histories are dropped.
+			advance_to(20);
+			let batch: BoundedVec<_, _> = vec![region.begin + 1].try_into().unwrap();
+			let info = Broker::drop_histories(RuntimeOrigin::signed(1), batch).unwrap();
+			assert_eq!(info.pays_fee, Pays::No);
+			assert_eq!(InstaPoolHistory::<Test>::iter().count(), 4);
+		});
+}
+
 #[test]
 fn request_core_count_works() {
 	TestExt::new().execute_with(|| {
@@ -1242,6 +1381,63 @@ fn renewal_requires_valid_status_and_sale_info() {
 	});
 }
 
+#[test]
+fn can_renew_reports_eligible_and_already_renewed_cores() {
+	TestExt::new().endow(1, 100_000).execute_with(|| {
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::Uninitialized));
+
+		assert_ok!(Broker::do_start_sales(100, 2));
+		advance_to(2);
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::NotAllowed));
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_assign(region, None, 1001, Final));
+
+		// Should now be renewable.
+		advance_to(6);
+		assert_ok!(Broker::can_renew(region.core));
+		assert_ok!(Broker::do_renew(1, region.core));
+		assert_eq!(
+			Broker::can_renew(region.core),
+			Err(RenewalIneligibilityReason::AlreadyRenewed)
+		);
+
+		// The renewed core is reported even if the sale is sold out.
+		let sale = SaleInfo::<Test>::get().unwrap();
+		SaleInfo::<Test>::put(SaleInfoRecord { cores_sold: sale.cores_offered, ..sale });
+		assert_eq!(
+			Broker::can_renew(region.core),
+			Err(RenewalIneligibilityReason::AlreadyRenewed)
+		);
+	});
+}
+
+#[test]
+fn can_renew_reports_ineligibility_reasons() {
+	TestExt::new().execute_with(|| {
+		assert_ok!(Broker::do_start_sales(200, 1));
+		let sale = SaleInfo::<Test>::get().unwrap();
+		let record = PotentialRenewalRecord {
+			price: 100,
+			completion: CompletionStatus::Partial(CoreMask::from_chunk(0, 20)),
+		};
+		PotentialRenewals::<Test>::insert(
+			PotentialRenewalId { core: 0, when: sale.region_begin },
+			&record,
+		);
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::IncompleteAssignment));
+
+		SaleInfo::<Test>::put(SaleInfoRecord { cores_sold: sale.cores_offered, ..sale.clone() });
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::SoldOut));
+
+		let core_count = Status::<Test>::get().unwrap().core_count;
+		SaleInfo::<Test>::put(SaleInfoRecord { first_core: core_count, ..sale });
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::Unavailable));
+
+		SaleInfo::<Test>::kill();
+		assert_eq!(Broker::can_renew(0), Err(RenewalIneligibilityReason::NoSales));
+	});
+}
+
 #[test]
 fn cannot_transfer_or_partition_or_interlace_unknown() {
 	TestExt::new().execute_with(|| {
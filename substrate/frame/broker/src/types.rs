@@ -175,6 +175,26 @@ pub struct PotentialRenewalRecord<Balance> {
 }
 pub type PotentialRenewalRecordOf<T> = PotentialRenewalRecord<BalanceOf<T>>;
 
+/// The reason why a core cannot be renewed in the ongoing sale.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RenewalIneligibilityReason {
+	/// The pallet has not yet been initialized.
+	Uninitialized,
+	/// There is no ongoing sale to renew the core in.
+	NoSales,
+	/// The cores of the ongoing sale are not yet available.
+	Unavailable,
+	/// All the cores of the ongoing sale have already been sold.
+	SoldOut,
+	/// The core is not in the set of potential renewals of the ongoing sale.
+	NotAllowed,
+	/// The core already has a workload for the region being sold, e.g. because it has already
+	/// been renewed in the ongoing sale.
+	AlreadyRenewed,
+	/// The workload on the core does not cover the whole region, so it cannot be renewed.
+	IncompleteAssignment,
+}
+
 /// General status of the system.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct StatusRecord {